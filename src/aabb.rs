@@ -0,0 +1,213 @@
+use num::Num;
+use super::float::Float;
+use super::{Ray, Vec3};
+
+/// Aabb is a generic axis-aligned bounding box, described by its `min` and
+/// `max` corners. A box is considered to contain the points on its boundary.
+///
+/// # Examples
+///
+/// ```
+/// use fiz_math::{Aabb, Vec3};
+///
+/// let b = Aabb::new(Vec3(0.0, 0.0, 0.0), Vec3(2.0, 4.0, 6.0));
+/// assert_eq!(b.center(), Vec3(1.0, 2.0, 3.0));
+/// assert_eq!(b.size(), Vec3(2.0, 4.0, 6.0));
+/// ```
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Aabb<T> {
+    pub min: Vec3<T>,
+    pub max: Vec3<T>,
+}
+
+impl<T> Aabb<T> {
+    /// new returns a box with the given `min` and `max` corners. Each component
+    /// of `min` is expected to be less than or equal to that of `max`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::{Aabb, Vec3};
+    ///
+    /// let b = Aabb::new(Vec3(0, 0, 0), Vec3(1, 1, 1));
+    /// assert_eq!(b.min, Vec3(0, 0, 0));
+    /// assert_eq!(b.max, Vec3(1, 1, 1));
+    /// ```
+    pub fn new(min: Vec3<T>, max: Vec3<T>) -> Self {
+        Aabb { min, max }
+    }
+}
+
+impl<T: PartialOrd + Copy> Aabb<T> {
+    /// from_points returns the smallest box containing all of the given points,
+    /// or None if there are no points.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::{Aabb, Vec3};
+    ///
+    /// let b = Aabb::from_points(vec![Vec3(1, 5, -2), Vec3(-3, 0, 4), Vec3(2, 1, 0)]);
+    /// assert_eq!(b, Some(Aabb::new(Vec3(-3, 0, -2), Vec3(2, 5, 4))));
+    /// assert_eq!(Aabb::<i32>::from_points(vec![]), None);
+    /// ```
+    pub fn from_points<I: IntoIterator<Item = Vec3<T>>>(points: I) -> Option<Self> {
+        let mut points = points.into_iter();
+        let first = points.next()?;
+        Some(points.fold(Aabb::new(first, first),
+                         |b, p| Aabb::new(b.min.min(p), b.max.max(p))))
+    }
+
+    /// contains tells if the given point lies inside of (or on the boundary of)
+    /// this box.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::{Aabb, Vec3};
+    ///
+    /// let b = Aabb::new(Vec3(0, 0, 0), Vec3(2, 2, 2));
+    /// assert!(b.contains(Vec3(1, 1, 1)));
+    /// assert!(b.contains(Vec3(2, 0, 2)));
+    /// assert!(!b.contains(Vec3(1, 3, 1)));
+    /// ```
+    pub fn contains(&self, point: Vec3<T>) -> bool {
+        point.0 >= self.min.0 && point.0 <= self.max.0 &&
+        point.1 >= self.min.1 && point.1 <= self.max.1 &&
+        point.2 >= self.min.2 && point.2 <= self.max.2
+    }
+
+    /// intersects tells if this box and the other one overlap. Boxes that only
+    /// touch at their boundaries are considered to intersect.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::{Aabb, Vec3};
+    ///
+    /// let a = Aabb::new(Vec3(0, 0, 0), Vec3(2, 2, 2));
+    /// assert!(a.intersects(&Aabb::new(Vec3(1, 1, 1), Vec3(3, 3, 3))));
+    /// assert!(a.intersects(&Aabb::new(Vec3(2, 0, 0), Vec3(4, 2, 2))));
+    /// assert!(!a.intersects(&Aabb::new(Vec3(0, 3, 0), Vec3(2, 4, 2))));
+    /// ```
+    pub fn intersects(&self, other: &Self) -> bool {
+        self.min.0 <= other.max.0 && self.max.0 >= other.min.0 &&
+        self.min.1 <= other.max.1 && self.max.1 >= other.min.1 &&
+        self.min.2 <= other.max.2 && self.max.2 >= other.min.2
+    }
+
+    /// union returns the smallest box containing both this box and the other
+    /// one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::{Aabb, Vec3};
+    ///
+    /// let a = Aabb::new(Vec3(0, 0, 0), Vec3(1, 1, 1));
+    /// let b = Aabb::new(Vec3(2, -1, 0), Vec3(3, 1, 1));
+    /// assert_eq!(a.union(&b), Aabb::new(Vec3(0, -1, 0), Vec3(3, 1, 1)));
+    /// ```
+    pub fn union(&self, other: &Self) -> Self {
+        Aabb::new(self.min.min(other.min), self.max.max(other.max))
+    }
+}
+
+impl<T: Num + Copy> Aabb<T> {
+    /// center returns the point at the center of this box.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::{Aabb, Vec3};
+    ///
+    /// let b = Aabb::new(Vec3(-1.0, 0.0, 2.0), Vec3(1.0, 4.0, 3.0));
+    /// assert_eq!(b.center(), Vec3(0.0, 2.0, 2.5));
+    /// ```
+    pub fn center(&self) -> Vec3<T> {
+        self.min.midpoint(self.max)
+    }
+
+    /// size returns the extent of this box along each axis.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::{Aabb, Vec3};
+    ///
+    /// let b = Aabb::new(Vec3(-1, 0, 2), Vec3(1, 4, 3));
+    /// assert_eq!(b.size(), Vec3(2, 4, 1));
+    /// ```
+    pub fn size(&self) -> Vec3<T> {
+        self.max - self.min
+    }
+}
+
+impl<T: Float> Aabb<T> {
+    /// ray_intersect returns the parameters `(near, far)` at which the given ray
+    /// enters and exits this box, or None if the ray misses it. The box is
+    /// tested using the slab method with the reciprocal of the ray direction,
+    /// and rays parallel to an axis are handled explicitly.
+    ///
+    /// If the ray starts inside of the box, `near` is negative; boxes lying
+    /// entirely behind the ray are not hit.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::{Aabb, Ray, Vec3};
+    ///
+    /// let b = Aabb::new(Vec3(-1.0, -1.0, -1.0), Vec3(1.0, 1.0, 1.0));
+    ///
+    /// // A ray hitting the box.
+    /// let r = Ray::new(Vec3(-5.0, 0.0, 0.0), Vec3(1.0, 0.0, 0.0));
+    /// assert_eq!(b.ray_intersect(&r), Some((4.0, 6.0)));
+    ///
+    /// // A ray missing the box.
+    /// let r = Ray::new(Vec3(-5.0, 2.0, 0.0), Vec3(1.0, 0.0, 0.0));
+    /// assert_eq!(b.ray_intersect(&r), None);
+    ///
+    /// // A ray starting inside of the box.
+    /// let r = Ray::new(Vec3(0.0, 0.0, 0.0), Vec3(0.0, 0.0, 1.0));
+    /// assert_eq!(b.ray_intersect(&r), Some((-1.0, 1.0)));
+    ///
+    /// // A ray pointing away from the box.
+    /// let r = Ray::new(Vec3(-5.0, 0.0, 0.0), Vec3(-1.0, 0.0, 0.0));
+    /// assert_eq!(b.ray_intersect(&r), None);
+    /// ```
+    ///
+    /// Rays travelling along a face of the box, parallel to it, still hit:
+    ///
+    /// ```
+    /// use fiz_math::{Aabb, Ray, Vec3};
+    ///
+    /// let b = Aabb::new(Vec3(0.0, 0.0, 0.0), Vec3(1.0, 1.0, 1.0));
+    /// let r = Ray::new(Vec3(-1.0, 0.0, 1.0), Vec3(2.0, 0.0, 0.0));
+    /// assert_eq!(b.ray_intersect(&r), Some((0.5, 1.0)));
+    /// ```
+    pub fn ray_intersect(&self, ray: &Ray<T>) -> Option<(T, T)> {
+        let inv = ray.direction.recip();
+        let mut near = T::neg_infinity();
+        let mut far = T::infinity();
+        for i in 0..3 {
+            let (o, min, max) = (ray.origin[i], self.min[i], self.max[i]);
+            if ray.direction[i] == T::zero() {
+                // Parallel to this slab; (min - o) * inv would be NaN when the
+                // origin lies on its boundary, so test the origin directly.
+                if o < min || o > max {
+                    return None;
+                }
+                continue;
+            }
+            let t1 = (min - o) * inv[i];
+            let t2 = (max - o) * inv[i];
+            near = near.max(t1.min(t2));
+            far = far.min(t1.max(t2));
+        }
+        if near > far || far < T::zero() {
+            None
+        } else {
+            Some((near, far))
+        }
+    }
+}
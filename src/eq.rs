@@ -0,0 +1,25 @@
+/// almost_equal_all tells if every pair of floating-point values is equal
+/// given an absolute tolerence value (see the almost_equal function for more
+/// details). Pairs are compared in order, stopping at the first unequal one.
+///
+/// # Examples
+///
+/// ```
+/// #[macro_use(almost_equal_all)]
+/// extern crate fiz_math;
+///
+/// fn main() {
+///     let (a, b) = ((1.0, 2.0, 3.0), (1.0, 2.05, 3.0));
+///     assert!(almost_equal_all!(0.1; (a.0, b.0), (a.1, b.1), (a.2, b.2)));
+///     assert!(!almost_equal_all!(0.01; (a.0, b.0), (a.1, b.1), (a.2, b.2)));
+///     assert!(!almost_equal_all!(0.1; (1.0, 1.0), (2.0, 3.0)));
+///     assert!(almost_equal_all!(0.1; (1.0, 1.0)));
+/// }
+/// ```
+#[macro_export]
+macro_rules! almost_equal_all {
+    ($abs_tol:expr; $(($a:expr, $b:expr)),+) => {{
+        let abs_tol = $abs_tol;
+        true $(&& $crate::Float::almost_equal($a, $b, abs_tol))+
+    }};
+}
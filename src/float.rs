@@ -1,6 +1,21 @@
 extern crate num;
 
 /// The default epsilon value used for floating point comparisons.
+///
+/// It is the tolerence used by `equal`; to compare with a different tolerence,
+/// pass it to `almost_equal` directly.
+///
+/// # Examples
+///
+/// ```
+/// use fiz_math::{EPSILON, Float};
+///
+/// let a = 1.0;
+/// let b = 1.000001;
+/// assert!(!a.equal(b));
+/// assert!(!a.almost_equal(b, EPSILON));
+/// assert!(a.almost_equal(b, 1e-5));
+/// ```
 pub static EPSILON: f64 = 1.0E-8;
 
 pub trait Float: num::Float{
@@ -23,6 +38,30 @@ pub trait Float: num::Float{
     /// ```
     fn almost_equal<T: Float>(self, y: Self, abs_tol: T) -> bool;
 
+    /// almost_equal_rel tells if the two floating-point values `self` and `y`
+    /// are considered equal within either the absolute tolerence `abs_tol`, or
+    /// the relative tolerence `rel_tol` scaled by the larger of the two
+    /// magnitudes.
+    ///
+    /// The absolute tolerence handles values near zero, where any relative
+    /// tolerence becomes vanishingly small, while the relative tolerence handles
+    /// large-magnitude values, where any reasonable absolute tolerence is
+    /// smaller than the gap between adjacent representable values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::Float;
+    ///
+    /// assert!(!1.0e20.almost_equal_rel(1.0000001e20, 1e-8, 0.0));
+    /// assert!(1.0e20.almost_equal_rel(1.0000001e20, 1e-8, 1e-6));
+    /// assert!(!1.0e20.almost_equal_rel(1.00001e20, 1e-8, 1e-6));
+    ///
+    /// assert!(!1e-10.almost_equal_rel(0.0, 0.0, 1e-6));
+    /// assert!(1e-10.almost_equal_rel(0.0, 1e-8, 1e-6));
+    /// ```
+    fn almost_equal_rel<T: Float>(self, y: Self, abs_tol: T, rel_tol: T) -> bool;
+
     /// equal is short-hand for `self.almost_equal(y, fiz_math::EPSILON)`.
     ///
     /// # Examples
@@ -60,6 +99,12 @@ impl<T: num::Float> Float for T {
         self == y || ((self - y).abs() <= T::from(abs_tol).unwrap() * r)
     }
 
+    fn almost_equal_rel<N: num::Float>(self, y: T, abs_tol: N, rel_tol: N) -> bool {
+        let diff = (self - y).abs();
+        self == y || diff <= T::from(abs_tol).unwrap() ||
+        diff <= T::from(rel_tol).unwrap() * self.abs().max(y.abs())
+    }
+
     fn equal(self, y: T) -> bool {
         self.almost_equal(y, T::from(EPSILON).unwrap())
     }
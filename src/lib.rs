@@ -1,22 +1,49 @@
 extern crate num;
+#[cfg(feature = "serde")]
+extern crate serde;
+#[cfg(feature = "bytemuck")]
+extern crate bytemuck;
 
 // Must re-export num for the unit! macro.
 #[doc(hidden)]
 pub use num as num_export;
 
 #[macro_use]mod swizzle;
+#[macro_use]mod eq;
 #[macro_use]pub mod unit_macro;
 
 mod vec2;
 mod vec3;
 mod vec4;
+mod point3;
+mod aabb;
+mod ray;
+mod plane;
+mod sphere;
+mod mat3;
+mod mat4;
+mod quat;
 mod float;
 mod clamp;
+mod ulps;
+#[cfg(feature = "serde")]
+mod serialize;
+#[cfg(feature = "bytemuck")]
+mod pod;
 pub mod unit;
 
 pub use num::{Zero, One, Num};
-pub use self::vec2::Vec2;
-pub use self::vec3::Vec3;
-pub use self::vec4::Vec4;
+pub use self::vec2::{Vec2, Vec2Iterator};
+pub use self::vec3::{Vec3, Vec3Iterator, Axis, Lex};
+pub use self::vec4::{Vec4, Vec4Iterator};
+pub use self::point3::Point3;
+pub use self::aabb::Aabb;
+pub use self::ray::Ray;
+pub use self::plane::Plane;
+pub use self::sphere::Sphere;
+pub use self::mat3::Mat3;
+pub use self::mat4::Mat4;
+pub use self::quat::Quaternion;
 pub use self::float::{EPSILON, Float};
 pub use self::clamp::Clamp;
+pub use self::ulps::Ulps;
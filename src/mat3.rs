@@ -0,0 +1,190 @@
+use std::ops::Mul;
+use std::cmp::PartialEq;
+use num::{Zero, One, Num};
+use num;
+use super::float::Float;
+use super::Vec3;
+
+/// Mat3 is a generic 3x3 matrix type, stored as three row vectors.
+///
+/// Vectors are treated as column vectors, i.e. `m * v` transforms the vector
+/// `v` by the matrix `m`, and `a * b` is the matrix which applies `b` first and
+/// then `a`.
+///
+/// # Examples
+///
+/// ```
+/// use fiz_math::{Mat3, Vec3};
+///
+/// let m = Mat3(Vec3(1, 2, 3),
+///              Vec3(4, 5, 6),
+///              Vec3(7, 8, 9));
+/// println!("{:?}", m);
+/// ```
+#[derive(Copy, Clone, Debug, Default)]
+pub struct Mat3<T>(pub Vec3<T>, pub Vec3<T>, pub Vec3<T>);
+
+impl<T> Mat3<T> {
+    /// transpose returns the transpose of this matrix, i.e. the matrix whose
+    /// rows are the columns of this one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::{Mat3, Vec3};
+    ///
+    /// let m = Mat3(Vec3(1, 2, 3),
+    ///              Vec3(4, 5, 6),
+    ///              Vec3(7, 8, 9));
+    /// assert_eq!(m.transpose(), Mat3(Vec3(1, 4, 7),
+    ///                                Vec3(2, 5, 8),
+    ///                                Vec3(3, 6, 9)));
+    /// assert_eq!(m.transpose().transpose(), m);
+    /// assert_eq!(Mat3::<i32>::identity().transpose(), Mat3::identity());
+    /// ```
+    pub fn transpose(self) -> Self {
+        let Mat3(a, b, c) = self;
+        Mat3(Vec3(a.0, b.0, c.0),
+             Vec3(a.1, b.1, c.1),
+             Vec3(a.2, b.2, c.2))
+    }
+}
+
+impl<T: Zero + One> Mat3<T> {
+    /// identity returns the identity matrix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::{Mat3, Vec3};
+    ///
+    /// let v = Vec3(1, 2, 3);
+    /// assert_eq!(Mat3::identity() * v, v);
+    /// ```
+    pub fn identity() -> Self {
+        Mat3(Vec3::unit_x(), Vec3::unit_y(), Vec3::unit_z())
+    }
+}
+
+impl<T: Num + Copy> Mat3<T> {
+    /// determinant returns the determinant of this matrix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::{Mat3, Vec3};
+    ///
+    /// assert_eq!(Mat3::<i32>::identity().determinant(), 1);
+    ///
+    /// let scale = Mat3(Vec3(2, 0, 0),
+    ///                  Vec3(0, 3, 0),
+    ///                  Vec3(0, 0, 4));
+    /// assert_eq!(scale.determinant(), 24);
+    ///
+    /// let m = Mat3(Vec3(1, 2, 3),
+    ///              Vec3(0, 1, 4),
+    ///              Vec3(5, 6, 0));
+    /// assert_eq!(m.determinant(), 1);
+    /// ```
+    pub fn determinant(self) -> T {
+        self.0.dot(self.1.cross(self.2))
+    }
+}
+
+impl<T: Float> Mat3<T> {
+    /// almost_equal tells if this matrix is equal to the other given an
+    /// absolute tolerence value (see the almost_equal function for more
+    /// details).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::{Mat3, Vec3};
+    ///
+    /// let a = Mat3::<f32>::identity();
+    /// let b = Mat3(Vec3(1.0, 0.0, 0.0),
+    ///              Vec3(0.0, 0.9, 0.0),
+    ///              Vec3(0.0, 0.0, 1.0));
+    /// assert!(a.almost_equal(b, 0.1000001));
+    /// assert!(!a.almost_equal(b, 0.1));
+    /// ```
+    pub fn almost_equal<N: num::Float>(self, other: Self, abs_tol: N) -> bool {
+        self.0.almost_equal(other.0, abs_tol) && self.1.almost_equal(other.1, abs_tol) &&
+        self.2.almost_equal(other.2, abs_tol)
+    }
+}
+
+impl<T: Num + Copy> Mul for Mat3<T> {
+    type Output = Self;
+
+    /// mul returns the matrix product of two matrices.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::{Mat3, Vec3};
+    ///
+    /// let a = Mat3(Vec3(1, 2, 3),
+    ///              Vec3(4, 5, 6),
+    ///              Vec3(7, 8, 9));
+    /// assert_eq!(a * Mat3::identity(), a);
+    /// assert_eq!(a * a, Mat3(Vec3(30, 36, 42),
+    ///                        Vec3(66, 81, 96),
+    ///                        Vec3(102, 126, 150)));
+    /// ```
+    ///
+    /// Multiplying two rotation matrices composes the rotations:
+    ///
+    /// ```
+    /// use std::f64::consts::FRAC_PI_2;
+    /// use fiz_math::{Mat3, Vec3};
+    ///
+    /// let (s, c) = FRAC_PI_2.sin_cos();
+    /// let quarter = Mat3(Vec3(c, -s, 0.0),
+    ///                    Vec3(s, c, 0.0),
+    ///                    Vec3(0.0, 0.0, 1.0));
+    /// let half = Mat3(Vec3(-1.0, 0.0, 0.0),
+    ///                 Vec3(0.0, -1.0, 0.0),
+    ///                 Vec3(0.0, 0.0, 1.0));
+    /// assert!((quarter * quarter).almost_equal(half, 1e-10));
+    /// ```
+    fn mul(self, _rhs: Self) -> Self {
+        let row = |r: Vec3<T>| _rhs.0 * r.0 + _rhs.1 * r.1 + _rhs.2 * r.2;
+        Mat3(row(self.0), row(self.1), row(self.2))
+    }
+}
+
+impl<T: Num + Copy> Mul<Vec3<T>> for Mat3<T> {
+    type Output = Vec3<T>;
+
+    /// mul transforms the given vector by this matrix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::{Mat3, Vec3};
+    ///
+    /// let scale = Mat3(Vec3(2, 0, 0),
+    ///                  Vec3(0, 3, 0),
+    ///                  Vec3(0, 0, 4));
+    /// assert_eq!(scale * Vec3(1, 1, 1), Vec3(2, 3, 4));
+    /// ```
+    fn mul(self, _rhs: Vec3<T>) -> Vec3<T> {
+        Vec3(self.0.dot(_rhs), self.1.dot(_rhs), self.2.dot(_rhs))
+    }
+}
+
+impl<T: PartialEq> PartialEq for Mat3<T> {
+    /// eq tests for component-wise binary equality of two matrices.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::Mat3;
+    ///
+    /// assert_eq!(Mat3::<i32>::identity(), Mat3::identity());
+    /// ```
+    fn eq(&self, _rhs: &Self) -> bool {
+        self.0 == _rhs.0 && self.1 == _rhs.1 && self.2 == _rhs.2
+    }
+}
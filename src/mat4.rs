@@ -0,0 +1,471 @@
+use std::ops::Mul;
+use std::cmp::PartialEq;
+use num::{Zero, One, Num};
+use num;
+use super::float::Float;
+use super::{Mat3, Vec3, Vec4};
+use super::unit::ToRad;
+
+/// Mat4 is a generic 4x4 matrix type, stored as four row vectors.
+///
+/// Vectors are treated as column vectors, i.e. `m * v` transforms the vector
+/// `v` by the matrix `m`, and `a * b` is the matrix which applies `b` first and
+/// then `a`.
+///
+/// # Examples
+///
+/// ```
+/// use fiz_math::{Mat4, Vec4};
+///
+/// let m = Mat4(Vec4(1, 2, 3, 4),
+///              Vec4(5, 6, 7, 8),
+///              Vec4(9, 10, 11, 12),
+///              Vec4(13, 14, 15, 16));
+/// println!("{:?}", m);
+/// ```
+#[derive(Copy, Clone, Debug, Default)]
+pub struct Mat4<T>(pub Vec4<T>, pub Vec4<T>, pub Vec4<T>, pub Vec4<T>);
+
+impl<T> Mat4<T> {
+    /// transpose returns the transpose of this matrix, i.e. the matrix whose
+    /// rows are the columns of this one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::{Mat4, Vec4};
+    ///
+    /// let m = Mat4(Vec4(1, 2, 3, 4),
+    ///              Vec4(5, 6, 7, 8),
+    ///              Vec4(9, 10, 11, 12),
+    ///              Vec4(13, 14, 15, 16));
+    /// assert_eq!(m.transpose(), Mat4(Vec4(1, 5, 9, 13),
+    ///                                Vec4(2, 6, 10, 14),
+    ///                                Vec4(3, 7, 11, 15),
+    ///                                Vec4(4, 8, 12, 16)));
+    /// assert_eq!(m.transpose().transpose(), m);
+    /// assert_eq!(Mat4::<i32>::identity().transpose(), Mat4::identity());
+    /// ```
+    pub fn transpose(self) -> Self {
+        let Mat4(a, b, c, d) = self;
+        Mat4(Vec4(a.0, b.0, c.0, d.0),
+             Vec4(a.1, b.1, c.1, d.1),
+             Vec4(a.2, b.2, c.2, d.2),
+             Vec4(a.3, b.3, c.3, d.3))
+    }
+}
+
+impl<T: Zero + One> Mat4<T> {
+    /// identity returns the identity matrix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::{Mat4, Vec4};
+    ///
+    /// let v = Vec4(1, 2, 3, 4);
+    /// assert_eq!(Mat4::identity() * v, v);
+    /// ```
+    pub fn identity() -> Self {
+        Mat4(Vec4::unit_x(), Vec4::unit_y(), Vec4::unit_z(), Vec4::unit_w())
+    }
+
+    /// translation returns a matrix which translates points by the given
+    /// vector.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::{Mat4, Vec3};
+    ///
+    /// let v = Vec3(10.0, 20.0, 30.0);
+    /// let p = Vec3(1.0, 2.0, 3.0);
+    /// assert_eq!(Mat4::translation(v).transform_point(p), p + v);
+    /// ```
+    pub fn translation(v: Vec3<T>) -> Self {
+        Mat4(Vec4(T::one(), T::zero(), T::zero(), v.0),
+             Vec4(T::zero(), T::one(), T::zero(), v.1),
+             Vec4(T::zero(), T::zero(), T::one(), v.2),
+             Vec4::unit_w())
+    }
+
+    /// scale returns a matrix which scales each axis by the corresponding
+    /// component of the given vector.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::{Mat4, Vec3};
+    ///
+    /// let p = Vec3(1.0, 2.0, 3.0);
+    /// assert_eq!(Mat4::scale(Vec3(2.0, 3.0, 4.0)).transform_point(p),
+    ///            Vec3(2.0, 6.0, 12.0));
+    /// ```
+    pub fn scale(v: Vec3<T>) -> Self {
+        Mat4(Vec4(v.0, T::zero(), T::zero(), T::zero()),
+             Vec4(T::zero(), v.1, T::zero(), T::zero()),
+             Vec4(T::zero(), T::zero(), v.2, T::zero()),
+             Vec4::unit_w())
+    }
+}
+
+impl<T: Num + Copy> Mat4<T> {
+    /// transform_point transforms the given point by this matrix. The point is
+    /// extended with a w component of one, transformed, and then divided by the
+    /// resulting w component (the perspective divide).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::{Mat4, Vec3, Vec4};
+    ///
+    /// let p = Vec3(1.0, 2.0, 3.0);
+    /// assert_eq!(Mat4::identity().transform_point(p), p);
+    ///
+    /// let translate = Mat4(Vec4(1.0, 0.0, 0.0, 10.0),
+    ///                      Vec4(0.0, 1.0, 0.0, 20.0),
+    ///                      Vec4(0.0, 0.0, 1.0, 30.0),
+    ///                      Vec4(0.0, 0.0, 0.0, 1.0));
+    /// assert_eq!(translate.transform_point(p), Vec3(11.0, 22.0, 33.0));
+    /// ```
+    pub fn transform_point(self, p: Vec3<T>) -> Vec3<T> {
+        let v = self * p.extend(T::one());
+        v.truncate().div_scalar(v.3)
+    }
+
+    /// determinant returns the determinant of this matrix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::{Mat4, Vec3, Vec4};
+    ///
+    /// assert_eq!(Mat4::<i32>::identity().determinant(), 1);
+    /// assert_eq!(Mat4::scale(Vec3(2, 3, 4)).determinant(), 24);
+    /// assert_eq!(Mat4::translation(Vec3(2, 3, 4)).determinant(), 1);
+    ///
+    /// let m = Mat4(Vec4(1, 0, 2, -1),
+    ///              Vec4(3, 0, 0, 5),
+    ///              Vec4(2, 1, 4, -3),
+    ///              Vec4(1, 0, 5, 0));
+    /// assert_eq!(m.determinant(), 30);
+    /// ```
+    pub fn determinant(self) -> T {
+        let r = self.0;
+        r.0 * self.minor(0, 0) - r.1 * self.minor(0, 1) + r.2 * self.minor(0, 2) -
+        r.3 * self.minor(0, 3)
+    }
+
+    /// minor returns the determinant of the 3x3 matrix formed by removing row
+    /// `i` and column `j` from this matrix.
+    fn minor(self, i: usize, j: usize) -> T {
+        let rows = [self.0, self.1, self.2, self.3];
+        let row = |k: usize| {
+            let v = rows[if k < i { k } else { k + 1 }];
+            let col = |k: usize| v[if k < j { k } else { k + 1 }];
+            Vec3(col(0), col(1), col(2))
+        };
+        Mat3(row(0), row(1), row(2)).determinant()
+    }
+}
+
+impl<T: Float> Mat4<T> {
+    /// rotation_x returns a matrix which rotates counter-clockwise about the x
+    /// axis by the given angle.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::{Mat4, Vec3};
+    /// use fiz_math::unit::Deg;
+    ///
+    /// let m = Mat4::rotation_x(Deg(90.0));
+    /// assert!(m.transform_point(Vec3::unit_y()).almost_equal(Vec3::unit_z(), 1e-10));
+    /// ```
+    pub fn rotation_x<A: ToRad<Output = T>>(angle: A) -> Self {
+        let (s, c) = angle.to_rad().0.sin_cos();
+        Mat4(Vec4::unit_x(),
+             Vec4(T::zero(), c, -s, T::zero()),
+             Vec4(T::zero(), s, c, T::zero()),
+             Vec4::unit_w())
+    }
+
+    /// rotation_y returns a matrix which rotates counter-clockwise about the y
+    /// axis by the given angle.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::{Mat4, Vec3};
+    /// use fiz_math::unit::Deg;
+    ///
+    /// let m = Mat4::rotation_y(Deg(90.0));
+    /// assert!(m.transform_point(Vec3::unit_z()).almost_equal(Vec3::unit_x(), 1e-10));
+    /// ```
+    pub fn rotation_y<A: ToRad<Output = T>>(angle: A) -> Self {
+        let (s, c) = angle.to_rad().0.sin_cos();
+        Mat4(Vec4(c, T::zero(), s, T::zero()),
+             Vec4::unit_y(),
+             Vec4(-s, T::zero(), c, T::zero()),
+             Vec4::unit_w())
+    }
+
+    /// rotation_z returns a matrix which rotates counter-clockwise about the z
+    /// axis by the given angle.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::f64::consts::FRAC_PI_2;
+    /// use fiz_math::{Mat4, Vec3};
+    /// use fiz_math::unit::Rad;
+    ///
+    /// let m = Mat4::rotation_z(Rad(FRAC_PI_2));
+    /// assert!(m.transform_point(Vec3::unit_x()).almost_equal(Vec3::unit_y(), 1e-10));
+    /// ```
+    pub fn rotation_z<A: ToRad<Output = T>>(angle: A) -> Self {
+        let (s, c) = angle.to_rad().0.sin_cos();
+        Mat4(Vec4(c, -s, T::zero(), T::zero()),
+             Vec4(s, c, T::zero(), T::zero()),
+             Vec4::unit_z(),
+             Vec4::unit_w())
+    }
+
+    /// perspective returns a right-handed perspective projection matrix, where
+    /// `fovy` is the vertical field of view, `aspect` is the ratio of width to
+    /// height, and `near` and `far` are the (positive) distances to the near
+    /// and far clipping planes.
+    ///
+    /// The camera looks down the negative z axis, and view-space depths are
+    /// mapped to the OpenGL clip-space range of -1 (near) to 1 (far). See
+    /// `perspective_zo` for the 0 to 1 range used by Vulkan and Direct3D.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::{Mat4, Vec3};
+    /// use fiz_math::unit::Deg;
+    ///
+    /// let m = Mat4::perspective(Deg(90.0), 1.0, 1.0, 100.0);
+    /// assert!(m.transform_point(Vec3(0.0, 0.0, -1.0)).almost_equal(Vec3(0.0, 0.0, -1.0), 1e-10));
+    /// assert!(m.transform_point(Vec3(0.0, 0.0, -100.0)).almost_equal(Vec3(0.0, 0.0, 1.0), 1e-10));
+    /// assert!(m.transform_point(Vec3(1.0, 1.0, -1.0)).almost_equal(Vec3(1.0, 1.0, -1.0), 1e-10));
+    /// ```
+    pub fn perspective<A: ToRad<Output = T>>(fovy: A, aspect: T, near: T, far: T) -> Self {
+        let two = T::one() + T::one();
+        let f = T::one() / (fovy.to_rad().0 / two).tan();
+        Mat4(Vec4(f / aspect, T::zero(), T::zero(), T::zero()),
+             Vec4(T::zero(), f, T::zero(), T::zero()),
+             Vec4(T::zero(), T::zero(), (far + near) / (near - far), two * far * near / (near - far)),
+             Vec4(T::zero(), T::zero(), -T::one(), T::zero()))
+    }
+
+    /// perspective_zo is like `perspective`, except view-space depths are
+    /// mapped to the Vulkan and Direct3D clip-space range of 0 (near) to 1
+    /// (far).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::{Mat4, Vec3};
+    /// use fiz_math::unit::Deg;
+    ///
+    /// let m = Mat4::perspective_zo(Deg(90.0), 1.0, 1.0, 100.0);
+    /// assert!(m.transform_point(Vec3(0.0, 0.0, -1.0)).almost_equal(Vec3(0.0, 0.0, 0.0), 1e-10));
+    /// assert!(m.transform_point(Vec3(0.0, 0.0, -100.0)).almost_equal(Vec3(0.0, 0.0, 1.0), 1e-10));
+    /// ```
+    pub fn perspective_zo<A: ToRad<Output = T>>(fovy: A, aspect: T, near: T, far: T) -> Self {
+        let two = T::one() + T::one();
+        let f = T::one() / (fovy.to_rad().0 / two).tan();
+        Mat4(Vec4(f / aspect, T::zero(), T::zero(), T::zero()),
+             Vec4(T::zero(), f, T::zero(), T::zero()),
+             Vec4(T::zero(), T::zero(), far / (near - far), far * near / (near - far)),
+             Vec4(T::zero(), T::zero(), -T::one(), T::zero()))
+    }
+
+    /// orthographic returns a right-handed orthographic projection matrix
+    /// mapping the given view-space box to the clip-space cube. As with
+    /// `perspective`, the camera looks down the negative z axis, `near` and
+    /// `far` are distances along it, and depths are mapped to the OpenGL range
+    /// of -1 (near) to 1 (far).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::{Mat4, Vec3};
+    ///
+    /// let m = Mat4::orthographic(0.0, 800.0, 0.0, 600.0, 1.0, 10.0);
+    /// assert!(m.transform_point(Vec3(0.0, 0.0, -1.0)).almost_equal(Vec3(-1.0, -1.0, -1.0), 1e-10));
+    /// assert!(m.transform_point(Vec3(800.0, 600.0, -10.0)).almost_equal(Vec3(1.0, 1.0, 1.0), 1e-10));
+    /// assert!(m.transform_point(Vec3(400.0, 300.0, -5.5)).almost_equal(Vec3(0.0, 0.0, 0.0), 1e-10));
+    /// ```
+    pub fn orthographic(left: T, right: T, bottom: T, top: T, near: T, far: T) -> Self {
+        let two = T::one() + T::one();
+        Mat4(Vec4(two / (right - left), T::zero(), T::zero(), -(right + left) / (right - left)),
+             Vec4(T::zero(), two / (top - bottom), T::zero(), -(top + bottom) / (top - bottom)),
+             Vec4(T::zero(), T::zero(), -two / (far - near), -(far + near) / (far - near)),
+             Vec4::unit_w())
+    }
+
+    /// look_at returns a right-handed view matrix for a camera positioned at
+    /// `eye` looking towards `target`, with `up` giving the upwards direction.
+    ///
+    /// In view space the camera sits at the origin looking down the negative z
+    /// axis, with positive x to the right and positive y up, matching the
+    /// `perspective` and `orthographic` projections.
+    ///
+    /// If `eye` and `target` are the same point, or `up` is parallel to the
+    /// viewing direction, then there is no unique view matrix and None is
+    /// returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::{Mat4, Vec3};
+    ///
+    /// let eye = Vec3(1.0, 2.0, 3.0);
+    /// let m = Mat4::look_at(eye, Vec3(1.0, 2.0, -7.0), Vec3::unit_y()).unwrap();
+    /// assert!(m.transform_point(eye).almost_equal(Vec3(0.0, 0.0, 0.0), 1e-10));
+    /// assert!(m.transform_point(Vec3(1.0, 2.0, -7.0)).almost_equal(Vec3(0.0, 0.0, -10.0), 1e-10));
+    /// assert!(m.transform_point(Vec3(2.0, 3.0, 3.0)).almost_equal(Vec3(1.0, 1.0, 0.0), 1e-10));
+    ///
+    /// assert!(Mat4::look_at(eye, eye, Vec3::unit_y()).is_none());
+    /// assert!(Mat4::look_at(eye, Vec3(1.0, 5.0, 3.0), Vec3::unit_y()).is_none());
+    /// ```
+    pub fn look_at(eye: Vec3<T>, target: Vec3<T>, up: Vec3<T>) -> Option<Self> {
+        (target - eye).normalize().and_then(|f| {
+            f.cross(up).normalize().map(|s| {
+                let u = s.cross(f);
+                Mat4(s.extend(-s.dot(eye)),
+                     u.extend(-u.dot(eye)),
+                     (-f).extend(f.dot(eye)),
+                     Vec4::unit_w())
+            })
+        })
+    }
+
+    /// inverse returns the inverse of this matrix, computed as the adjugate
+    /// divided by the determinant. If the determinant is zero (see the `equal`
+    /// function) then the matrix is singular and None is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::{Mat4, Vec3};
+    /// use fiz_math::unit::Deg;
+    ///
+    /// let m = Mat4::translation(Vec3(1.0, 2.0, 3.0)) * Mat4::rotation_y(Deg(30.0)) *
+    ///         Mat4::scale(Vec3(2.0, 4.0, 8.0));
+    /// let inv = m.inverse().unwrap();
+    /// assert!((m * inv).almost_equal(Mat4::identity(), 1e-10));
+    /// assert!((inv * m).almost_equal(Mat4::identity(), 1e-10));
+    ///
+    /// let p = Vec3(5.0, 6.0, 7.0);
+    /// assert!(inv.transform_point(m.transform_point(p)).almost_equal(p, 1e-10));
+    ///
+    /// assert!(Mat4::scale(Vec3(1.0, 0.0, 1.0)).inverse().is_none());
+    /// ```
+    pub fn inverse(self) -> Option<Self> {
+        let det = self.determinant();
+        if det.equal(T::zero()) {
+            return None;
+        }
+        let cofactor = |i: usize, j: usize| {
+            let m = self.minor(i, j);
+            if (i + j) & 1 == 0 { m } else { -m }
+        };
+        let row = |i: usize| {
+            Vec4(cofactor(0, i), cofactor(1, i), cofactor(2, i), cofactor(3, i)) / det
+        };
+        Some(Mat4(row(0), row(1), row(2), row(3)))
+    }
+
+    /// almost_equal tells if this matrix is equal to the other given an
+    /// absolute tolerence value (see the almost_equal function for more
+    /// details).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::{Mat4, Vec4};
+    ///
+    /// let a = Mat4::<f32>::identity();
+    /// let b = Mat4(Vec4(1.0, 0.0, 0.0, 0.0),
+    ///              Vec4(0.0, 0.9, 0.0, 0.0),
+    ///              Vec4(0.0, 0.0, 1.0, 0.0),
+    ///              Vec4(0.0, 0.0, 0.0, 1.0));
+    /// assert!(a.almost_equal(b, 0.1000001));
+    /// assert!(!a.almost_equal(b, 0.1));
+    /// ```
+    pub fn almost_equal<N: num::Float>(self, other: Self, abs_tol: N) -> bool {
+        self.0.almost_equal(other.0, abs_tol) && self.1.almost_equal(other.1, abs_tol) &&
+        self.2.almost_equal(other.2, abs_tol) && self.3.almost_equal(other.3, abs_tol)
+    }
+}
+
+impl<T: Num + Copy> Mul for Mat4<T> {
+    type Output = Self;
+
+    /// mul returns the matrix product of two matrices.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::{Mat4, Vec4};
+    ///
+    /// let a = Mat4(Vec4(1, 2, 3, 4),
+    ///              Vec4(5, 6, 7, 8),
+    ///              Vec4(9, 10, 11, 12),
+    ///              Vec4(13, 14, 15, 16));
+    /// assert_eq!(a * Mat4::identity(), a);
+    /// assert_eq!(Mat4::identity() * a, a);
+    /// assert_eq!(a * a, Mat4(Vec4(90, 100, 110, 120),
+    ///                        Vec4(202, 228, 254, 280),
+    ///                        Vec4(314, 356, 398, 440),
+    ///                        Vec4(426, 484, 542, 600)));
+    /// ```
+    fn mul(self, _rhs: Self) -> Self {
+        let row = |r: Vec4<T>| _rhs.0 * r.0 + _rhs.1 * r.1 + _rhs.2 * r.2 + _rhs.3 * r.3;
+        Mat4(row(self.0), row(self.1), row(self.2), row(self.3))
+    }
+}
+
+impl<T: Num + Copy> Mul<Vec4<T>> for Mat4<T> {
+    type Output = Vec4<T>;
+
+    /// mul transforms the given vector by this matrix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::{Mat4, Vec4};
+    ///
+    /// let translate = Mat4(Vec4(1, 0, 0, 10),
+    ///                      Vec4(0, 1, 0, 20),
+    ///                      Vec4(0, 0, 1, 30),
+    ///                      Vec4(0, 0, 0, 1));
+    /// assert_eq!(translate * Vec4(1, 2, 3, 1), Vec4(11, 22, 33, 1));
+    /// assert_eq!(translate * Vec4(1, 2, 3, 0), Vec4(1, 2, 3, 0));
+    /// ```
+    fn mul(self, _rhs: Vec4<T>) -> Vec4<T> {
+        Vec4(self.0.dot(_rhs),
+             self.1.dot(_rhs),
+             self.2.dot(_rhs),
+             self.3.dot(_rhs))
+    }
+}
+
+impl<T: PartialEq> PartialEq for Mat4<T> {
+    /// eq tests for component-wise binary equality of two matrices.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::Mat4;
+    ///
+    /// assert_eq!(Mat4::<i32>::identity(), Mat4::identity());
+    /// ```
+    fn eq(&self, _rhs: &Self) -> bool {
+        self.0 == _rhs.0 && self.1 == _rhs.1 && self.2 == _rhs.2 && self.3 == _rhs.3
+    }
+}
@@ -0,0 +1,97 @@
+use super::float::Float;
+use super::Vec3;
+
+/// Plane is a generic infinite plane in 3D space, consisting of the points `p`
+/// for which `normal.dot(p) == distance`. The `normal` is expected to be of unit
+/// length, in which case `distance` is the signed distance of the plane from
+/// the origin along it.
+///
+/// # Examples
+///
+/// ```
+/// use fiz_math::{Plane, Vec3};
+///
+/// // The plane y = 2, facing up.
+/// let p = Plane::new(Vec3(0.0, 1.0, 0.0), 2.0);
+/// assert_eq!(p.signed_distance(Vec3(5.0, 3.0, -1.0)), 1.0);
+/// ```
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct Plane<T> {
+    pub normal: Vec3<T>,
+    pub distance: T,
+}
+
+impl<T> Plane<T> {
+    /// new returns a plane with the given unit normal and distance from the
+    /// origin.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::{Plane, Vec3};
+    ///
+    /// let p = Plane::new(Vec3(0.0, 0.0, 1.0), -3.0);
+    /// assert_eq!(p.normal, Vec3(0.0, 0.0, 1.0));
+    /// assert_eq!(p.distance, -3.0);
+    /// ```
+    pub fn new(normal: Vec3<T>, distance: T) -> Self {
+        Plane { normal, distance }
+    }
+}
+
+impl<T: Float> Plane<T> {
+    /// from_points returns the plane passing through the three given points. The
+    /// normal faces the side from which `a`, `b`, `c` appear counter-clockwise.
+    /// None is returned if the points are collinear (or coincident), as they do
+    /// not define a unique plane.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::{Plane, Vec3};
+    ///
+    /// let p = Plane::from_points(Vec3(0.0, 0.0, 1.0),
+    ///                            Vec3(1.0, 0.0, 1.0),
+    ///                            Vec3(0.0, 1.0, 1.0)).unwrap();
+    /// assert_eq!(p, Plane::new(Vec3(0.0, 0.0, 1.0), 1.0));
+    ///
+    /// assert_eq!(Plane::from_points(Vec3(0.0, 0.0, 0.0),
+    ///                               Vec3(1.0, 1.0, 1.0),
+    ///                               Vec3(2.0, 2.0, 2.0)), None);
+    /// ```
+    pub fn from_points(a: Vec3<T>, b: Vec3<T>, c: Vec3<T>) -> Option<Self> {
+        let normal = (b - a).cross(c - a).normalize()?;
+        Some(Plane::new(normal, normal.dot(a)))
+    }
+
+    /// signed_distance returns the distance from the plane to the given point,
+    /// which is positive on the side the normal faces and negative on the other.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::{Plane, Vec3};
+    ///
+    /// let p = Plane::new(Vec3(1.0, 0.0, 0.0), 1.0);
+    /// assert_eq!(p.signed_distance(Vec3(3.0, 7.0, 7.0)), 2.0);
+    /// assert_eq!(p.signed_distance(Vec3(1.0, 7.0, 7.0)), 0.0);
+    /// assert_eq!(p.signed_distance(Vec3(-1.0, 7.0, 7.0)), -2.0);
+    /// ```
+    pub fn signed_distance(self, point: Vec3<T>) -> T {
+        self.normal.dot(point) - self.distance
+    }
+
+    /// project_point returns the point on the plane closest to the given point.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::{Plane, Vec3};
+    ///
+    /// let p = Plane::new(Vec3(0.0, 1.0, 0.0), 2.0);
+    /// assert_eq!(p.project_point(Vec3(4.0, -3.0, 1.0)), Vec3(4.0, 2.0, 1.0));
+    /// ```
+    pub fn project_point(self, point: Vec3<T>) -> Vec3<T> {
+        point - self.normal.mul_scalar(self.signed_distance(point))
+    }
+}
@@ -0,0 +1,37 @@
+//! Bytemuck support for the vector types, enabled by the `bytemuck` feature.
+//!
+//! Vectors of the primitive numeric types implement `Pod` and `Zeroable`, such
+//! that slices of them can be reinterpreted as slices of their components (or
+//! bytes) without copying, e.g. when uploading vertex data to the GPU.
+//!
+//! # Examples
+//!
+//! ```
+//! extern crate bytemuck;
+//! extern crate fiz_math;
+//!
+//! use fiz_math::Vec3;
+//!
+//! # fn main() {
+//! let verts = [Vec3(1.0f32, 2.0, 3.0), Vec3(4.0, 5.0, 6.0)];
+//! let floats: &[f32] = bytemuck::cast_slice(&verts);
+//! assert_eq!(floats, &[1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+//! assert_eq!(bytemuck::cast_slice::<_, u8>(&verts).len(), 24);
+//! # }
+//! ```
+
+use bytemuck::{Pod, Zeroable};
+use super::{Vec2, Vec3, Vec4};
+
+macro_rules! impl_pod {
+    ($($ty:ty),*) => ($(
+        unsafe impl Zeroable for Vec2<$ty> {}
+        unsafe impl Pod for Vec2<$ty> {}
+        unsafe impl Zeroable for Vec3<$ty> {}
+        unsafe impl Pod for Vec3<$ty> {}
+        unsafe impl Zeroable for Vec4<$ty> {}
+        unsafe impl Pod for Vec4<$ty> {}
+    )*);
+}
+
+impl_pod! { f32, f64, i8, u8, i16, u16, i32, u32, i64, u64, isize, usize }
@@ -0,0 +1,232 @@
+use std::ops::{Add, Sub, AddAssign, SubAssign};
+use num::{Zero, Num};
+use super::float::Float;
+use super::Vec3;
+
+/// Point3 is a generic three-component (3D) point type, representing a
+/// position in space rather than a direction. Unlike Vec3 it only supports the
+/// operations that are meaningful for positions: the difference of two points
+/// is a vector, and a point offset by a vector is a point.
+///
+/// # Examples
+///
+/// ```
+/// use fiz_math::{Point3, Vec3};
+///
+/// let a = Point3(1.0, 2.0, 3.0);
+/// let b = Point3(4.0, 6.0, 3.0);
+/// assert_eq!(b - a, Vec3(3.0, 4.0, 0.0));
+/// assert_eq!(a + Vec3(3.0, 4.0, 0.0), b);
+/// assert_eq!(b - Vec3(3.0, 4.0, 0.0), a);
+/// ```
+///
+/// Adding two points together is meaningless, and does not compile:
+///
+/// ```compile_fail
+/// use fiz_math::Point3;
+///
+/// let c = Point3(1.0, 2.0, 3.0) + Point3(4.0, 5.0, 6.0);
+/// ```
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[repr(C)]
+pub struct Point3<T>(pub T, pub T, pub T);
+
+impl<T> From<Vec3<T>> for Point3<T> {
+    /// from converts a vector into the point it reaches from the origin.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::{Point3, Vec3};
+    ///
+    /// let p: Point3<i32> = Vec3(1, 2, 3).into();
+    /// assert_eq!(p, Point3(1, 2, 3));
+    /// ```
+    fn from(v: Vec3<T>) -> Self {
+        Point3(v.0, v.1, v.2)
+    }
+}
+
+impl<T> From<Point3<T>> for Vec3<T> {
+    /// from converts a point into the vector from the origin to it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::{Point3, Vec3};
+    ///
+    /// let v: Vec3<i32> = Point3(1, 2, 3).into();
+    /// assert_eq!(v, Vec3(1, 2, 3));
+    /// ```
+    fn from(p: Point3<T>) -> Self {
+        Vec3(p.0, p.1, p.2)
+    }
+}
+
+impl<T> Point3<T> {
+    /// to_vec returns the vector from the origin to this point.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::{Point3, Vec3};
+    ///
+    /// assert_eq!(Point3(1, 2, 3).to_vec(), Vec3(1, 2, 3));
+    /// ```
+    pub fn to_vec(self) -> Vec3<T> {
+        Vec3(self.0, self.1, self.2)
+    }
+}
+
+impl<T: Zero> Point3<T> {
+    /// origin returns the point at the origin, i.e. Point3(0, 0, 0).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::Point3;
+    ///
+    /// assert_eq!(Point3::origin(), Point3(0, 0, 0));
+    /// ```
+    pub fn origin() -> Self {
+        Point3(T::zero(), T::zero(), T::zero())
+    }
+}
+
+impl<T: Sub<Output = T>> Sub for Point3<T> {
+    type Output = Vec3<T>;
+
+    /// sub returns the vector from the `_rhs` point to this one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::{Point3, Vec3};
+    ///
+    /// assert_eq!(Point3(4, 5, 6) - Point3(1, 1, 1), Vec3(3, 4, 5));
+    /// ```
+    fn sub(self, _rhs: Self) -> Vec3<T> {
+        Vec3(self.0 - _rhs.0, self.1 - _rhs.1, self.2 - _rhs.2)
+    }
+}
+
+impl<T: Add<Output = T>> Add<Vec3<T>> for Point3<T> {
+    type Output = Self;
+
+    /// add returns this point offset by the given vector.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::{Point3, Vec3};
+    ///
+    /// assert_eq!(Point3(1, 2, 3) + Vec3(1, 1, 1), Point3(2, 3, 4));
+    /// ```
+    fn add(self, _rhs: Vec3<T>) -> Self {
+        Point3(self.0 + _rhs.0, self.1 + _rhs.1, self.2 + _rhs.2)
+    }
+}
+
+impl<T: Sub<Output = T>> Sub<Vec3<T>> for Point3<T> {
+    type Output = Self;
+
+    /// sub returns this point offset by the negation of the given vector.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::{Point3, Vec3};
+    ///
+    /// assert_eq!(Point3(1, 2, 3) - Vec3(1, 1, 1), Point3(0, 1, 2));
+    /// ```
+    fn sub(self, _rhs: Vec3<T>) -> Self {
+        Point3(self.0 - _rhs.0, self.1 - _rhs.1, self.2 - _rhs.2)
+    }
+}
+
+impl<T: Add<Output = T> + Copy> AddAssign<Vec3<T>> for Point3<T> {
+    /// add_assign offsets this point by the given vector.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::{Point3, Vec3};
+    ///
+    /// let mut p = Point3(1, 2, 3);
+    /// p += Vec3(1, 1, 1);
+    /// assert_eq!(p, Point3(2, 3, 4));
+    /// ```
+    fn add_assign(&mut self, _rhs: Vec3<T>) {
+        *self = *self + _rhs
+    }
+}
+
+impl<T: Sub<Output = T> + Copy> SubAssign<Vec3<T>> for Point3<T> {
+    /// sub_assign offsets this point by the negation of the given vector.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::{Point3, Vec3};
+    ///
+    /// let mut p = Point3(1, 2, 3);
+    /// p -= Vec3(1, 1, 1);
+    /// assert_eq!(p, Point3(0, 1, 2));
+    /// ```
+    fn sub_assign(&mut self, _rhs: Vec3<T>) {
+        *self = *self - _rhs
+    }
+}
+
+impl<T: Num + PartialOrd + Copy> Point3<T> {
+    /// distance_sq returns the squared distance between this point and the
+    /// other one. The difference of each component is taken such that unsigned
+    /// points do not underflow.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::Point3;
+    ///
+    /// assert_eq!(Point3(1, 1, 1).distance_sq(Point3(2, 3, 3)), 9);
+    /// assert_eq!(Point3(2u32, 0, 0).distance_sq(Point3(1, 0, 0)), 1);
+    /// ```
+    pub fn distance_sq(self, other: Self) -> T {
+        self.to_vec().distance_sq(other.to_vec())
+    }
+}
+
+impl<T: Float> Point3<T> {
+    /// distance returns the distance between this point and the other one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::Point3;
+    ///
+    /// assert_eq!(Point3(1.0, 1.0, 1.0).distance(Point3(2.0, 3.0, 3.0)), 3.0);
+    /// ```
+    pub fn distance(self, other: Self) -> T {
+        (other - self).length()
+    }
+
+    /// lerp returns the point linearly interpolated between this point and
+    /// the other one by `t`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::Point3;
+    ///
+    /// let a = Point3(0.0, 0.0, 0.0);
+    /// let b = Point3(2.0, 4.0, 8.0);
+    /// assert_eq!(a.lerp(b, 0.5), Point3(1.0, 2.0, 4.0));
+    /// assert_eq!(a.lerp(b, 1.0), b);
+    ///
+    /// let c = Point3(1e20, 0.0, 0.0);
+    /// assert_eq!(c.lerp(b, 1.0), b);
+    /// ```
+    pub fn lerp(self, other: Self, t: T) -> Self {
+        Point3(self.0.lerp(other.0, t), self.1.lerp(other.1, t), self.2.lerp(other.2, t))
+    }
+}
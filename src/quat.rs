@@ -0,0 +1,330 @@
+use std::ops::Mul;
+use std::cmp::PartialEq;
+use num;
+use super::float::Float;
+use super::{Mat3, Mat4, Vec3, Vec4};
+use super::unit::ToRad;
+
+/// Quaternion is a generic quaternion type, typically used to represent
+/// rotations in 3D space. It is stored as `Quaternion(x, y, z, w)`, where `w`
+/// is the scalar part and `(x, y, z)` is the vector part.
+///
+/// # Examples
+///
+/// ```
+/// use fiz_math::Quaternion;
+///
+/// let q = Quaternion(0.0, 0.0, 0.0, 1.0);
+/// println!("{:?}", q);
+/// ```
+#[derive(Copy, Clone, Debug)]
+pub struct Quaternion<T>(pub T, pub T, pub T, pub T);
+
+impl<T: Float> Quaternion<T> {
+    /// identity returns the identity quaternion, which represents no rotation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::Quaternion;
+    ///
+    /// assert_eq!(Quaternion::identity(), Quaternion(0.0, 0.0, 0.0, 1.0));
+    /// ```
+    pub fn identity() -> Self {
+        Quaternion(T::zero(), T::zero(), T::zero(), T::one())
+    }
+
+    /// from_axis_angle returns a quaternion representing a counter-clockwise
+    /// rotation about the given axis by the given angle. The axis is assumed to
+    /// be normalized; if it is not, the resulting quaternion will not be of unit
+    /// length and will not represent a pure rotation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::{Quaternion, Vec3};
+    /// use fiz_math::unit::Deg;
+    ///
+    /// let q = Quaternion::from_axis_angle(Vec3::unit_z(), Deg(180.0));
+    /// assert!(q.almost_equal(Quaternion(0.0, 0.0, 1.0, 0.0), 1e-10));
+    /// ```
+    pub fn from_axis_angle<A: ToRad<Output = T>>(axis: Vec3<T>, angle: A) -> Self {
+        let (s, c) = (angle.to_rad().0 / (T::one() + T::one())).sin_cos();
+        Quaternion(axis.0 * s, axis.1 * s, axis.2 * s, c)
+    }
+
+    /// to_mat3 returns the rotation matrix equivalent to this quaternion, which
+    /// is assumed to be normalized.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::{Mat3, Quaternion, Vec3};
+    /// use fiz_math::unit::Deg;
+    ///
+    /// let m = Quaternion::from_axis_angle(Vec3::unit_z(), Deg(90.0)).to_mat3();
+    /// assert!((m * Vec3::unit_x()).almost_equal(Vec3::unit_y(), 1e-10));
+    /// assert_eq!(Quaternion::<f64>::identity().to_mat3(), Mat3::identity());
+    /// ```
+    pub fn to_mat3(self) -> Mat3<T> {
+        let Quaternion(x, y, z, w) = self;
+        let two = T::one() + T::one();
+        Mat3(Vec3(T::one() - two * (y * y + z * z), two * (x * y - z * w), two * (x * z + y * w)),
+             Vec3(two * (x * y + z * w), T::one() - two * (x * x + z * z), two * (y * z - x * w)),
+             Vec3(two * (x * z - y * w), two * (y * z + x * w), T::one() - two * (x * x + y * y)))
+    }
+
+    /// to_mat4 returns the rotation matrix equivalent to this quaternion, which
+    /// is assumed to be normalized.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::{Mat4, Quaternion, Vec3};
+    /// use fiz_math::unit::Deg;
+    ///
+    /// let q = Quaternion::from_axis_angle(Vec3::unit_z(), Deg(90.0));
+    /// assert!(q.to_mat4().transform_point(Vec3::unit_x()).almost_equal(Vec3::unit_y(), 1e-10));
+    /// assert!(q.to_mat4().almost_equal(Mat4::rotation_z(Deg(90.0)), 1e-10));
+    /// ```
+    pub fn to_mat4(self) -> Mat4<T> {
+        let m = self.to_mat3();
+        Mat4(m.0.extend(T::zero()),
+             m.1.extend(T::zero()),
+             m.2.extend(T::zero()),
+             Vec4::unit_w())
+    }
+
+    /// conjugate returns the conjugate of this quaternion, i.e. the quaternion
+    /// with its vector part negated. For unit quaternions this is the inverse
+    /// rotation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::Quaternion;
+    ///
+    /// let q = Quaternion(1.0, 2.0, 3.0, 4.0);
+    /// assert_eq!(q.conjugate(), Quaternion(-1.0, -2.0, -3.0, 4.0));
+    /// ```
+    pub fn conjugate(self) -> Self {
+        Quaternion(-self.0, -self.1, -self.2, self.3)
+    }
+
+    /// length returns the magnitude of this quaternion.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::Quaternion;
+    ///
+    /// assert_eq!(Quaternion(1.0, 1.0, 1.0, 1.0).length(), 2.0);
+    /// ```
+    pub fn length(self) -> T {
+        (self.0 * self.0 + self.1 * self.1 + self.2 * self.2 + self.3 * self.3).sqrt()
+    }
+
+    /// normalize returns the normalized (i.e. unit length) version of this
+    /// quaternion. If the quaternion's length is zero and division by zero
+    /// would occur, then None is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::Quaternion;
+    ///
+    /// assert_eq!(Quaternion(1.0, 1.0, 1.0, 1.0).normalize(),
+    ///            Some(Quaternion(0.5, 0.5, 0.5, 0.5)));
+    /// assert_eq!(Quaternion(0.0, 0.0, 0.0, 0.0).normalize(), None);
+    /// ```
+    pub fn normalize(self) -> Option<Self> {
+        let length = self.length();
+        if length == T::zero() {
+            None
+        } else {
+            Some(Quaternion(self.0 / length, self.1 / length, self.2 / length, self.3 / length))
+        }
+    }
+
+    /// rotate applies the rotation represented by this quaternion, which is
+    /// assumed to be normalized, to the given vector. This is equivalent to
+    /// `q * v * q.conjugate()` but is computed more efficiently.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::{Quaternion, Vec3};
+    /// use fiz_math::unit::Deg;
+    ///
+    /// let q = Quaternion::from_axis_angle(Vec3::unit_z(), Deg(90.0));
+    /// assert!(q.rotate(Vec3::unit_x()).almost_equal(Vec3::unit_y(), 1e-10));
+    ///
+    /// let v = Vec3(1.0, 2.0, 3.0);
+    /// let q = Quaternion::from_axis_angle(Vec3(1.0, 1.0, 0.0).normalize().unwrap(), Deg(60.0));
+    /// assert!(q.rotate(v).almost_equal(q.to_mat3() * v, 1e-10));
+    /// ```
+    pub fn rotate(self, v: Vec3<T>) -> Vec3<T> {
+        let u = Vec3(self.0, self.1, self.2);
+        let t = u.cross(v) * (T::one() + T::one());
+        v + t * self.3 + u.cross(t)
+    }
+
+    /// dot returns the dot product of two quaternions.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::Quaternion;
+    ///
+    /// assert_eq!(Quaternion(1.0, 2.0, 3.0, 4.0).dot(Quaternion(5.0, 6.0, 7.0, 8.0)), 70.0);
+    /// ```
+    pub fn dot(self, other: Self) -> T {
+        self.0 * other.0 + self.1 * other.1 + self.2 * other.2 + self.3 * other.3
+    }
+
+    /// slerp performs a spherical linear interpolation between the `self` and
+    /// `other` unit quaternions, i.e. it rotates from one orientation to the
+    /// other at a constant angular velocity. The `t` parameter is a number in
+    /// the range 0.0 - 1.0.
+    ///
+    /// Since `q` and `-q` represent the same rotation, the interpolation always
+    /// takes the shorter path (so the result at `t == 1.0` may be `-other`).
+    /// When the quaternions are nearly parallel a normalized linear
+    /// interpolation is used instead, to avoid dividing by a tiny sine.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::{Quaternion, Vec3};
+    /// use fiz_math::unit::Deg;
+    ///
+    /// let a = Quaternion::identity();
+    /// let b = Quaternion::from_axis_angle(Vec3::unit_z(), Deg(90.0));
+    /// assert!(a.slerp(b, 0.0).almost_equal(a, 1e-10));
+    /// assert!(a.slerp(b, 1.0).almost_equal(b, 1e-10));
+    ///
+    /// let half = Quaternion::from_axis_angle(Vec3::unit_z(), Deg(45.0));
+    /// assert!(a.slerp(b, 0.5).almost_equal(half, 1e-10));
+    ///
+    /// let quarter = Quaternion::from_axis_angle(Vec3::unit_z(), Deg(22.5));
+    /// assert!(a.slerp(b, 0.25).almost_equal(quarter, 1e-10));
+    /// ```
+    ///
+    /// The shorter path is taken even if `other` is in the opposite hemisphere:
+    ///
+    /// ```
+    /// use fiz_math::{Quaternion, Vec3};
+    /// use fiz_math::unit::Deg;
+    ///
+    /// let a = Quaternion::identity();
+    /// let b = Quaternion::from_axis_angle(Vec3::unit_z(), Deg(90.0));
+    /// let neg_b = Quaternion(-b.0, -b.1, -b.2, -b.3);
+    /// assert!(a.slerp(neg_b, 0.5).almost_equal(a.slerp(b, 0.5), 1e-10));
+    /// ```
+    ///
+    /// Nearly parallel quaternions are handled without loss of precision:
+    ///
+    /// ```
+    /// use fiz_math::{Quaternion, Vec3};
+    /// use fiz_math::unit::Deg;
+    ///
+    /// let a = Quaternion::from_axis_angle(Vec3::unit_x(), Deg(30.0));
+    /// assert!(a.slerp(a, 0.5).almost_equal(a, 1e-10));
+    ///
+    /// let b = Quaternion::from_axis_angle(Vec3::unit_x(), Deg(30.001));
+    /// let mid = Quaternion::from_axis_angle(Vec3::unit_x(), Deg(30.0005));
+    /// assert!(a.slerp(b, 0.5).almost_equal(mid, 1e-10));
+    /// ```
+    pub fn slerp(self, other: Self, t: T) -> Self {
+        let mut cos = self.dot(other);
+        let mut other = other;
+        if cos < T::zero() {
+            cos = -cos;
+            other = Quaternion(-other.0, -other.1, -other.2, -other.3);
+        }
+
+        if cos > T::from(0.9995).unwrap() {
+            let q = Quaternion(self.0.lerp(other.0, t),
+                               self.1.lerp(other.1, t),
+                               self.2.lerp(other.2, t),
+                               self.3.lerp(other.3, t));
+            let length = q.length();
+            return Quaternion(q.0 / length, q.1 / length, q.2 / length, q.3 / length);
+        }
+
+        let theta = cos.acos();
+        let sin = theta.sin();
+        let a = ((T::one() - t) * theta).sin() / sin;
+        let b = (t * theta).sin() / sin;
+        Quaternion(a * self.0 + b * other.0,
+                   a * self.1 + b * other.1,
+                   a * self.2 + b * other.2,
+                   a * self.3 + b * other.3)
+    }
+
+    /// almost_equal tells if this quaternion is equal to the other given an
+    /// absolute tolerence value (see the almost_equal function for more
+    /// details).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::Quaternion;
+    ///
+    /// let a = Quaternion::<f32>::identity();
+    /// let b = Quaternion(0.0, 0.0, 0.0, 0.9);
+    /// assert!(a.almost_equal(b, 0.1000001));
+    /// assert!(!a.almost_equal(b, 0.1));
+    /// ```
+    pub fn almost_equal<N: num::Float>(self, other: Self, abs_tol: N) -> bool {
+        self.0.almost_equal(other.0, abs_tol) && self.1.almost_equal(other.1, abs_tol) &&
+        self.2.almost_equal(other.2, abs_tol) && self.3.almost_equal(other.3, abs_tol)
+    }
+}
+
+impl<T: Float> Mul for Quaternion<T> {
+    type Output = Self;
+
+    /// mul returns the Hamilton product of two quaternions. For rotations,
+    /// `a * b` is the rotation which applies `b` first and then `a`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::Quaternion;
+    ///
+    /// let q = Quaternion(1.0, 2.0, 3.0, 4.0);
+    /// assert_eq!(q * Quaternion::identity(), q);
+    /// assert_eq!(Quaternion::identity() * q, q);
+    ///
+    /// let i = Quaternion(1.0, 0.0, 0.0, 0.0);
+    /// let j = Quaternion(0.0, 1.0, 0.0, 0.0);
+    /// let k = Quaternion(0.0, 0.0, 1.0, 0.0);
+    /// assert_eq!(i * j, k);
+    /// assert_eq!(j * i, Quaternion(0.0, 0.0, -1.0, 0.0));
+    /// ```
+    fn mul(self, _rhs: Self) -> Self {
+        let Quaternion(x1, y1, z1, w1) = self;
+        let Quaternion(x2, y2, z2, w2) = _rhs;
+        Quaternion(w1 * x2 + x1 * w2 + y1 * z2 - z1 * y2,
+                   w1 * y2 - x1 * z2 + y1 * w2 + z1 * x2,
+                   w1 * z2 + x1 * y2 - y1 * x2 + z1 * w2,
+                   w1 * w2 - x1 * x2 - y1 * y2 - z1 * z2)
+    }
+}
+
+impl<T: PartialEq> PartialEq for Quaternion<T> {
+    /// eq tests for component-wise binary equality of two quaternions.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::Quaternion;
+    ///
+    /// assert_eq!(Quaternion(1.0, 2.0, 3.0, 4.0), Quaternion(1.0, 2.0, 3.0, 4.0));
+    /// assert!(Quaternion(1.0, 2.0, 3.0, 4.0) != Quaternion(1.0, 2.0, 3.0, -4.0));
+    /// ```
+    fn eq(&self, _rhs: &Self) -> bool {
+        self.0 == _rhs.0 && self.1 == _rhs.1 && self.2 == _rhs.2 && self.3 == _rhs.3
+    }
+}
@@ -0,0 +1,76 @@
+use num::Num;
+use super::float::Float;
+use super::Vec3;
+
+/// Ray is a generic half-line starting at `origin` and extending in the
+/// `direction` vector, typically used for raytracing and picking.
+///
+/// # Examples
+///
+/// ```
+/// use fiz_math::{Ray, Vec3};
+///
+/// let r = Ray::new(Vec3(1.0, 0.0, 0.0), Vec3(0.0, 1.0, 0.0));
+/// assert_eq!(r.at(2.0), Vec3(1.0, 2.0, 0.0));
+/// ```
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Ray<T> {
+    pub origin: Vec3<T>,
+    pub direction: Vec3<T>,
+}
+
+impl<T> Ray<T> {
+    /// new returns a ray with the given origin and direction. The direction is
+    /// stored as-is; use new_normalized for a ray with a unit direction.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::{Ray, Vec3};
+    ///
+    /// let r = Ray::new(Vec3(0, 0, 0), Vec3(0, 0, 2));
+    /// assert_eq!(r.direction, Vec3(0, 0, 2));
+    /// ```
+    pub fn new(origin: Vec3<T>, direction: Vec3<T>) -> Self {
+        Ray { origin, direction }
+    }
+}
+
+impl<T: Float> Ray<T> {
+    /// new_normalized returns a ray with the given origin and the normalized
+    /// direction, such that the parameter of `at` is the distance travelled
+    /// along the ray. None is returned if the direction is the zero vector.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::{Ray, Vec3};
+    ///
+    /// let r = Ray::new_normalized(Vec3(0.0, 0.0, 0.0), Vec3(0.0, 0.0, 2.0)).unwrap();
+    /// assert_eq!(r.direction, Vec3(0.0, 0.0, 1.0));
+    /// assert_eq!(r.at(3.0), Vec3(0.0, 0.0, 3.0));
+    ///
+    /// assert_eq!(Ray::new_normalized(Vec3(1.0, 1.0, 1.0), Vec3(0.0, 0.0, 0.0)), None);
+    /// ```
+    pub fn new_normalized(origin: Vec3<T>, direction: Vec3<T>) -> Option<Self> {
+        Some(Ray::new(origin, direction.normalize()?))
+    }
+}
+
+impl<T: Num + Copy> Ray<T> {
+    /// at returns the point along the ray at parameter `t`, i.e.
+    /// `origin + direction * t`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::{Ray, Vec3};
+    ///
+    /// let r = Ray::new(Vec3(1, 2, 3), Vec3(1, 0, -1));
+    /// assert_eq!(r.at(0), Vec3(1, 2, 3));
+    /// assert_eq!(r.at(2), Vec3(3, 2, 1));
+    /// ```
+    pub fn at(&self, t: T) -> Vec3<T> {
+        self.origin + self.direction.mul_scalar(t)
+    }
+}
@@ -0,0 +1,43 @@
+//! Serde support for the vector types, enabled by the `serde` feature.
+//!
+//! Vectors are serialized as a sequence of their components, i.e. a `Vec3` is
+//! represented as `[x, y, z]`.
+//!
+//! # Examples
+//!
+//! ```
+//! extern crate serde_json;
+//! extern crate fiz_math;
+//!
+//! use fiz_math::Vec3;
+//!
+//! # fn main() {
+//! let v = Vec3(1.0f32, 2.5, -3.0);
+//! let json = serde_json::to_string(&v).unwrap();
+//! assert_eq!(json, "[1.0,2.5,-3.0]");
+//! assert_eq!(serde_json::from_str::<Vec3<f32>>(&json).unwrap(), v);
+//! # }
+//! ```
+
+use serde::{Serialize, Serializer, Deserialize, Deserializer};
+use super::{Vec2, Vec3, Vec4};
+
+macro_rules! impl_serde {
+    ($ident:ident, $n:expr, $($field:tt),*) => (
+        impl<T: Serialize> Serialize for $ident<T> {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                ($(&self.$field),*).serialize(serializer)
+            }
+        }
+
+        impl<'de, T: Deserialize<'de>> Deserialize<'de> for $ident<T> {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                <[T; $n]>::deserialize(deserializer).map($ident::from)
+            }
+        }
+    );
+}
+
+impl_serde!(Vec2, 2, 0, 1);
+impl_serde!(Vec3, 3, 0, 1, 2);
+impl_serde!(Vec4, 4, 0, 1, 2, 3);
@@ -0,0 +1,128 @@
+use super::float::Float;
+use super::{Ray, Vec3};
+
+/// Sphere is a generic sphere described by its `center` and `radius`. A sphere
+/// is considered to contain the points on its surface.
+///
+/// # Examples
+///
+/// ```
+/// use fiz_math::{Sphere, Vec3};
+///
+/// let s = Sphere::new(Vec3(0.0, 0.0, 0.0), 2.0);
+/// assert!(s.contains(Vec3(1.0, 1.0, 1.0)));
+/// assert!(!s.contains(Vec3(2.0, 2.0, 0.0)));
+/// ```
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct Sphere<T> {
+    pub center: Vec3<T>,
+    pub radius: T,
+}
+
+impl<T> Sphere<T> {
+    /// new returns a sphere with the given center and radius.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::{Sphere, Vec3};
+    ///
+    /// let s = Sphere::new(Vec3(1.0, 2.0, 3.0), 0.5);
+    /// assert_eq!(s.center, Vec3(1.0, 2.0, 3.0));
+    /// assert_eq!(s.radius, 0.5);
+    /// ```
+    pub fn new(center: Vec3<T>, radius: T) -> Self {
+        Sphere { center, radius }
+    }
+}
+
+impl<T: Float> Sphere<T> {
+    /// contains tells if the given point lies inside of (or on the surface of)
+    /// this sphere.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::{Sphere, Vec3};
+    ///
+    /// let s = Sphere::new(Vec3(1.0, 0.0, 0.0), 1.0);
+    /// assert!(s.contains(Vec3(1.5, 0.5, 0.0)));
+    /// assert!(s.contains(Vec3(2.0, 0.0, 0.0)));
+    /// assert!(!s.contains(Vec3(-0.5, 0.0, 0.0)));
+    /// ```
+    pub fn contains(self, point: Vec3<T>) -> bool {
+        self.center.distance_sq(point) <= self.radius * self.radius
+    }
+
+    /// intersects_sphere tells if this sphere and the other one overlap. Spheres
+    /// that only touch at their surfaces are considered to intersect.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::{Sphere, Vec3};
+    ///
+    /// let a = Sphere::new(Vec3(0.0, 0.0, 0.0), 1.0);
+    /// assert!(a.intersects_sphere(Sphere::new(Vec3(1.5, 0.0, 0.0), 1.0)));
+    /// assert!(a.intersects_sphere(Sphere::new(Vec3(0.0, 3.0, 0.0), 2.0)));
+    /// assert!(!a.intersects_sphere(Sphere::new(Vec3(0.0, 0.0, 3.0), 1.0)));
+    /// ```
+    pub fn intersects_sphere(self, other: Self) -> bool {
+        let r = self.radius + other.radius;
+        self.center.distance_sq(other.center) <= r * r
+    }
+
+    /// ray_intersect returns the smallest non-negative parameter at which the
+    /// given ray hits the surface of this sphere, or None if it misses. The
+    /// parameter is found by solving the quadratic `|origin + direction * t -
+    /// center|^2 = radius^2`; rays starting inside of the sphere hit it on the
+    /// way out.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::{Ray, Sphere, Vec3};
+    ///
+    /// let s = Sphere::new(Vec3(0.0, 0.0, 0.0), 1.0);
+    ///
+    /// // A ray hitting the sphere head-on.
+    /// let r = Ray::new(Vec3(-5.0, 0.0, 0.0), Vec3(1.0, 0.0, 0.0));
+    /// assert_eq!(s.ray_intersect(&r), Some(4.0));
+    ///
+    /// // A ray just grazing (tangent to) the sphere.
+    /// let r = Ray::new(Vec3(-5.0, 1.0, 0.0), Vec3(1.0, 0.0, 0.0));
+    /// assert_eq!(s.ray_intersect(&r), Some(5.0));
+    ///
+    /// // A ray missing the sphere.
+    /// let r = Ray::new(Vec3(-5.0, 1.5, 0.0), Vec3(1.0, 0.0, 0.0));
+    /// assert_eq!(s.ray_intersect(&r), None);
+    ///
+    /// // A ray starting inside of the sphere.
+    /// let r = Ray::new(Vec3(0.0, 0.0, 0.0), Vec3(0.0, 2.0, 0.0));
+    /// assert_eq!(s.ray_intersect(&r), Some(0.5));
+    ///
+    /// // A ray pointing away from the sphere.
+    /// let r = Ray::new(Vec3(-5.0, 0.0, 0.0), Vec3(-1.0, 0.0, 0.0));
+    /// assert_eq!(s.ray_intersect(&r), None);
+    /// ```
+    pub fn ray_intersect(self, ray: &Ray<T>) -> Option<T> {
+        let oc = ray.origin - self.center;
+        let a = ray.direction.length_sq();
+        if a == T::zero() {
+            return None;
+        }
+        let half_b = oc.dot(ray.direction);
+        let c = oc.length_sq() - self.radius * self.radius;
+        let discriminant = half_b * half_b - a * c;
+        if discriminant < T::zero() {
+            return None;
+        }
+        let sqrt_d = discriminant.sqrt();
+        let near = (-half_b - sqrt_d) / a;
+        if near >= T::zero() {
+            return Some(near);
+        }
+        let far = (-half_b + sqrt_d) / a;
+        if far >= T::zero() { Some(far) } else { None }
+    }
+}
@@ -2,24 +2,28 @@
 macro_rules! swizzle {
     (x, $ident:ident ) => {
 		impl<T> $ident<T> {
+			/// x returns the first (x) component of the vector.
 			pub fn x(self) -> T { self.0 }
 		}
     };
 
     (y, $ident:ident ) => {
 		impl<T> $ident<T> {
+			/// y returns the second (y) component of the vector.
 			pub fn y(self) -> T { self.1 }
 		}
     };
 
     (z, $ident:ident ) => {
 		impl<T> $ident<T> {
+			/// z returns the third (z) component of the vector.
 			pub fn z(self) -> T { self.2 }
 		}
     };
 
     (w, $ident:ident ) => {
 		impl<T> $ident<T> {
+			/// w returns the fourth (w) component of the vector.
 			pub fn w(self) -> T { self.3 }
 		}
     };
@@ -33,6 +37,16 @@ macro_rules! swizzle {
 		}
     };
 
+	(2, z, $ident:ident ) => {
+		impl<T: Copy> $ident<T> {
+			pub fn xz(self) -> Vec2<T> { Vec2(self.0, self.2) }
+			pub fn yz(self) -> Vec2<T> { Vec2(self.1, self.2) }
+			pub fn zx(self) -> Vec2<T> { Vec2(self.2, self.0) }
+			pub fn zy(self) -> Vec2<T> { Vec2(self.2, self.1) }
+			pub fn zz(self) -> Vec2<T> { Vec2(self.2, self.2) }
+		}
+    };
+
 	(3, $ident:ident ) => {
 		impl<T: Copy> $ident<T> {
 			pub fn xxx(self) -> Vec3<T> { Vec3(self.0, self.0, self.0) }
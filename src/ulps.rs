@@ -0,0 +1,65 @@
+pub trait Ulps{
+    /// ulps_equal tells if the two floating-point values `self` and `other` are
+    /// within `max_ulps` units in the last place of each other, i.e. if there
+    /// are at most `max_ulps - 1` representable values between them.
+    ///
+    /// Unlike an absolute tolerence, this scales naturally with the magnitude
+    /// of the values being compared. Positive and negative zero are considered
+    /// equal, and NaN is never equal to anything.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::Ulps;
+    ///
+    /// let a = 1.0f32;
+    /// let b = f32::from_bits(a.to_bits() + 1);
+    /// let c = f32::from_bits(a.to_bits() + 2);
+    /// assert!(a.ulps_equal(a, 0));
+    /// assert!(a.ulps_equal(b, 1));
+    /// assert!(!a.ulps_equal(c, 1));
+    /// assert!(c.ulps_equal(a, 2));
+    ///
+    /// assert!(0.0f32.ulps_equal(-0.0, 0));
+    /// assert!(!std::f32::NAN.ulps_equal(std::f32::NAN, 1000));
+    /// ```
+    ///
+    /// ```
+    /// use fiz_math::Ulps;
+    ///
+    /// let a = 1.0e100f64;
+    /// let b = f64::from_bits(a.to_bits() + 3);
+    /// assert!(a.ulps_equal(b, 4));
+    /// assert!(!a.ulps_equal(b, 2));
+    /// assert!(!1.0f64.ulps_equal(-1.0, 1000));
+    /// ```
+    fn ulps_equal(self, other: Self, max_ulps: u32) -> bool;
+}
+
+macro_rules! impl_ulps {
+    ($($ty:ty, $bits:ty);*) => ($(
+        impl Ulps for $ty {
+            fn ulps_equal(self, other: $ty, max_ulps: u32) -> bool {
+                if self.is_nan() || other.is_nan() {
+                    return false;
+                }
+                if self == other {
+                    return true;
+                }
+
+                // Map the bit representations onto integers which are ordered
+                // the same way as the floating-point values themselves.
+                let sign = !(<$bits>::max_value() >> 1);
+                let ordered = |x: $ty| {
+                    let b = x.to_bits();
+                    if b & sign != 0 { !b } else { b | sign }
+                };
+                let (a, b) = (ordered(self), ordered(other));
+                let distance = if a > b { a - b } else { b - a };
+                distance <= max_ulps as $bits
+            }
+        }
+    )*);
+}
+
+impl_ulps! { f32, u32; f64, u64 }
@@ -1,6 +1,7 @@
 #![allow(dead_code)]
 
-use std::ops::{Add, Sub, Neg, Mul, Div};
+use std::ops::{Add, Sub, Neg, Mul, Div, AddAssign, SubAssign, MulAssign, DivAssign,
+               Index, IndexMut};
 use std::cmp::{PartialEq, PartialOrd, Ordering};
 pub use num::{Zero, One, Num};
 use num;
@@ -8,10 +9,11 @@ use super::float::Float;
 use super::Vec3;
 use super::unit::{ToRad, Rad};
 use std::fmt;
+use std::hash::{Hash, Hasher};
 use clamp::Clamp;
 use std::iter::IntoIterator;
 
-/// Vec2 is a generic two-component vector type.
+/// Vec2 is a generic two-component (2D) vector type.
 ///
 /// # Examples
 ///
@@ -33,12 +35,26 @@ use std::iter::IntoIterator;
 /// let y = Vec2(MM(1.0), MM(5.1));
 /// assert!(x.almost_equal(y, 0.1));
 /// ```
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, Debug, Default)]
+#[repr(C)]
 pub struct Vec2<T>(pub T, pub T);
 
 impl<T: Copy> IntoIterator for Vec2<T> {
     type Item = T;
     type IntoIter = Vec2Iterator<T>;
+
+    /// into_iter returns an iterator over the components of the vector, in
+    /// order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::Vec2;
+    ///
+    /// let v = Vec2(1, 2);
+    /// assert_eq!(v.into_iter().collect::<Vec<_>>(), vec![1, 2]);
+    /// assert_eq!(v.into_iter().sum::<i32>(), 3);
+    /// ```
     fn into_iter(self) -> Self::IntoIter {
         Vec2Iterator {
             v: self,
@@ -47,6 +63,7 @@ impl<T: Copy> IntoIterator for Vec2<T> {
     }
 }
 
+/// Vec2Iterator is an iterator over the components of a Vec2.
 pub struct Vec2Iterator<T> {
     v: Vec2<T>,
     index: usize,
@@ -65,6 +82,180 @@ impl<T: Copy> Iterator for Vec2Iterator<T> {
     }
 }
 
+impl<T> Index<usize> for Vec2<T> {
+    type Output = T;
+
+    /// index returns a reference to the component at the given index, i.e.
+    /// `v[0]` is the x component. It panics if the index is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::Vec2;
+    ///
+    /// let v = Vec2(1, 2);
+    /// assert_eq!(v[1], 2);
+    /// ```
+    fn index(&self, i: usize) -> &T {
+        match i {
+            0 => &self.0,
+            1 => &self.1,
+            _ => panic!("Vec2 index out of bounds: the len is 2 but the index is {}", i),
+        }
+    }
+}
+
+impl<T> IndexMut<usize> for Vec2<T> {
+    /// index_mut returns a mutable reference to the component at the given
+    /// index. It panics if the index is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::Vec2;
+    ///
+    /// let mut v = Vec2(1, 2);
+    /// v[1] = 9;
+    /// assert_eq!(v[1], 9);
+    /// ```
+    fn index_mut(&mut self, i: usize) -> &mut T {
+        match i {
+            0 => &mut self.0,
+            1 => &mut self.1,
+            _ => panic!("Vec2 index out of bounds: the len is 2 but the index is {}", i),
+        }
+    }
+}
+
+impl<T> From<(T, T)> for Vec2<T> {
+    /// from converts a tuple into a vector.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::Vec2;
+    ///
+    /// let v: Vec2<f32> = (1.0, 2.0).into();
+    /// assert_eq!(v, Vec2(1.0, 2.0));
+    /// ```
+    fn from(v: (T, T)) -> Self {
+        Vec2(v.0, v.1)
+    }
+}
+
+impl<T> From<Vec2<T>> for (T, T) {
+    /// from converts a vector into a tuple.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::Vec2;
+    ///
+    /// let t: (f32, f32) = Vec2(1.0, 2.0).into();
+    /// assert_eq!(t, (1.0, 2.0));
+    /// ```
+    fn from(v: Vec2<T>) -> Self {
+        (v.0, v.1)
+    }
+}
+
+impl<T> From<[T; 2]> for Vec2<T> {
+    /// from converts an array into a vector.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::Vec2;
+    ///
+    /// let v: Vec2<f32> = [1.0, 2.0].into();
+    /// assert_eq!(v, Vec2(1.0, 2.0));
+    /// ```
+    fn from(v: [T; 2]) -> Self {
+        let [x, y] = v;
+        Vec2(x, y)
+    }
+}
+
+impl<T> From<Vec2<T>> for [T; 2] {
+    /// from converts a vector into an array.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::Vec2;
+    ///
+    /// let a: [f32; 2] = Vec2::from([1.0, 2.0]).into();
+    /// assert_eq!(a, [1.0, 2.0]);
+    /// ```
+    fn from(v: Vec2<T>) -> Self {
+        [v.0, v.1]
+    }
+}
+
+impl<T> Vec2<T> {
+    /// map returns a vector whose components are the result of applying the
+    /// function `f` to each component of this vector. The component type may
+    /// change.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::Vec2;
+    ///
+    /// assert_eq!(Vec2(1.0, 2.0).map(|c| c as i32), Vec2(1, 2));
+    /// ```
+    pub fn map<U, F: Fn(T) -> U>(self, f: F) -> Vec2<U> {
+        Vec2(f(self.0), f(self.1))
+    }
+
+    /// zip returns a vector whose components are the result of applying the
+    /// function `f` to each pair of components from this vector and the other
+    /// one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::Vec2;
+    ///
+    /// let a = Vec2(1.0f32, 5.0);
+    /// let b = Vec2(4.0, 2.0);
+    /// assert_eq!(a.zip(b, |x, y| x.max(y)), Vec2(4.0, 5.0));
+    /// assert_eq!(a.zip(b, |x, y| x.min(y)), Vec2(1.0, 2.0));
+    /// ```
+    pub fn zip<U, V, F: Fn(T, U) -> V>(self, other: Vec2<U>, f: F) -> Vec2<V> {
+        Vec2(f(self.0, other.0), f(self.1, other.1))
+    }
+
+    /// extend returns a three-component vector with the components of this
+    /// vector and the given `z` component.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::{Vec2, Vec3};
+    ///
+    /// assert_eq!(Vec2(1, 2).extend(3), Vec3(1, 2, 3));
+    /// ```
+    pub fn extend(self, z: T) -> Vec3<T> {
+        Vec3(self.0, self.1, z)
+    }
+}
+
+impl<T: Copy> Vec2<T> {
+    /// splat returns a vector with every component set to `v`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::Vec2;
+    ///
+    /// assert_eq!(Vec2::splat(5), Vec2(5, 5));
+    /// ```
+    pub fn splat(v: T) -> Self {
+        Vec2(v, v)
+    }
+}
+
 impl<T: fmt::Display> fmt::Display for Vec2<T> {
     /// fmt formats the vector.
     ///
@@ -101,6 +292,34 @@ impl<T: One> One for Vec2<T> {
     }
 }
 
+impl<T: Zero + One> Vec2<T> {
+    /// unit_x returns the unit vector along the x axis.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::Vec2;
+    ///
+    /// assert_eq!(Vec2::<f32>::unit_x(), Vec2(1.0, 0.0));
+    /// ```
+    pub fn unit_x() -> Self {
+        Vec2(T::one(), T::zero())
+    }
+
+    /// unit_y returns the unit vector along the y axis.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::Vec2;
+    ///
+    /// assert_eq!(Vec2::<f32>::unit_y(), Vec2(0.0, 1.0));
+    /// ```
+    pub fn unit_y() -> Self {
+        Vec2(T::zero(), T::one())
+    }
+}
+
 impl<T: Float> Vec2<T> {
     /// almost_equal tells if this vector is equal to the other given an absolute
     /// tolerence value (see the almost_equal function for more details).
@@ -116,7 +335,26 @@ impl<T: Float> Vec2<T> {
     /// assert!(!a.almost_equal(b, 0.1));
     /// ```
     pub fn almost_equal<N: num::Float>(self, other: Self, abs_tol: N) -> bool {
-        self.0.almost_equal(other.0, abs_tol) && self.1.almost_equal(other.1, abs_tol)
+        almost_equal_all!(abs_tol; (self.0, other.0), (self.1, other.1))
+    }
+
+    /// almost_equal_rel tells if this vector is equal to the other given an
+    /// absolute and a relative tolerence value (see the almost_equal_rel
+    /// function for more details).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::Vec2;
+    ///
+    /// let a = Vec2(1.0e20, 1.0e20);
+    /// let b = Vec2(1.0e20, 1.0000001e20);
+    /// assert!(!a.almost_equal(b, 1e-8));
+    /// assert!(a.almost_equal_rel(b, 1e-8, 1e-6));
+    /// ```
+    pub fn almost_equal_rel<N: num::Float>(self, other: Self, abs_tol: N, rel_tol: N) -> bool {
+        self.0.almost_equal_rel(other.0, abs_tol, rel_tol) &&
+        self.1.almost_equal_rel(other.1, abs_tol, rel_tol)
     }
 
     /// is_nan tells if all of this vectors components are NaN.
@@ -152,6 +390,50 @@ impl<T: Float> Vec2<T> {
     pub fn round(&self) -> Self {
         Vec2(self.0.round(), self.1.round())
     }
+
+    /// floor returns the vector with each component rounded down to the
+    /// nearest integer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::Vec2;
+    ///
+    /// assert_eq!(Vec2(1.4, 2.6).floor(), Vec2(1.0, 2.0))
+    /// ```
+    pub fn floor(&self) -> Self {
+        Vec2(self.0.floor(), self.1.floor())
+    }
+
+    /// ceil returns the vector with each component rounded up to the nearest
+    /// integer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::Vec2;
+    ///
+    /// assert_eq!(Vec2(1.4, 2.6).ceil(), Vec2(2.0, 3.0))
+    /// ```
+    pub fn ceil(&self) -> Self {
+        Vec2(self.0.ceil(), self.1.ceil())
+    }
+}
+
+impl<T: num::Signed> Vec2<T> {
+    /// abs returns the vector with the absolute value of each component.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::Vec2;
+    ///
+    /// assert_eq!(Vec2(-1.0, 2.0).abs(), Vec2(1.0, 2.0));
+    /// assert_eq!(Vec2(-1, 2).abs(), Vec2(1, 2));
+    /// ```
+    pub fn abs(&self) -> Self {
+        Vec2(self.0.abs(), self.1.abs())
+    }
 }
 
 impl<T: Add<Output = T>> Add for Vec2<T> {
@@ -187,6 +469,36 @@ impl<T: Add<Output = T> + Copy> Vec2<T> {
     pub fn add_scalar(self, _rhs: T) -> Self {
         Vec2(self.0 + _rhs, self.1 + _rhs)
     }
+
+    /// sum returns the sum of all of the components of the vector.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::Vec2;
+    ///
+    /// assert_eq!(Vec2(1, 2).sum(), 3);
+    /// ```
+    pub fn sum(self) -> T {
+        self.0 + self.1
+    }
+}
+
+impl<T: Add<Output = T> + Copy> AddAssign for Vec2<T> {
+    /// add_assign performs in-place component-wise addition of two vectors.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::Vec2;
+    ///
+    /// let mut a = Vec2(1, 2);
+    /// a += Vec2(4, 5);
+    /// assert_eq!(a, Vec2(5, 7));
+    /// ```
+    fn add_assign(&mut self, _rhs: Self) {
+        *self = *self + _rhs;
+    }
 }
 
 impl<T: Neg<Output = T>> Neg for Vec2<T> {
@@ -241,6 +553,23 @@ impl<T: Sub<Output = T> + Copy> Vec2<T> {
     }
 }
 
+impl<T: Sub<Output = T> + Copy> SubAssign for Vec2<T> {
+    /// sub_assign performs in-place component-wise subtraction of two vectors.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::Vec2;
+    ///
+    /// let mut a = Vec2(1, 2);
+    /// a -= Vec2(4, 5);
+    /// assert_eq!(a, Vec2(-3, -3));
+    /// ```
+    fn sub_assign(&mut self, _rhs: Self) {
+        *self = *self - _rhs;
+    }
+}
+
 impl<T: Mul<Output = T>> Mul for Vec2<T> {
     type Output = Self;
 
@@ -274,6 +603,74 @@ impl<T: Mul<Output = T> + Copy> Vec2<T> {
     pub fn mul_scalar(self, _rhs: T) -> Self {
         Vec2(self.0 * _rhs, self.1 * _rhs)
     }
+
+    /// product returns the product of all of the components of the vector,
+    /// e.g. the volume of a box whose size is the vector.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::Vec2;
+    ///
+    /// assert_eq!(Vec2(2, 3).product(), 6);
+    /// ```
+    pub fn product(self) -> T {
+        self.0 * self.1
+    }
+}
+
+impl<T: Mul<Output = T> + Copy> Mul<T> for Vec2<T> {
+    type Output = Self;
+
+    /// mul performs scalar multiplication on a vector, it is identical to
+    /// mul_scalar.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::Vec2;
+    ///
+    /// assert_eq!(Vec2(1, 2) * 2, Vec2(2, 4));
+    /// assert_eq!(Vec2(1.5, 2.0) * 2.0, Vec2(3.0, 4.0));
+    /// ```
+    fn mul(self, _rhs: T) -> Self {
+        Vec2(self.0 * _rhs, self.1 * _rhs)
+    }
+}
+
+impl<T: Mul<Output = T> + Copy> MulAssign for Vec2<T> {
+    /// mul_assign performs in-place component-wise multiplication of two
+    /// vectors.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::Vec2;
+    ///
+    /// let mut a = Vec2(1, 2);
+    /// a *= Vec2(4, 5);
+    /// assert_eq!(a, Vec2(4, 10));
+    /// ```
+    fn mul_assign(&mut self, _rhs: Self) {
+        *self = *self * _rhs;
+    }
+}
+
+impl<T: Mul<Output = T> + Copy> MulAssign<T> for Vec2<T> {
+    /// mul_assign performs in-place scalar multiplication on a vector.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::Vec2;
+    ///
+    /// let mut a = Vec2(1.0, 2.0);
+    /// a *= 2.0;
+    /// assert_eq!(a, Vec2(2.0, 4.0));
+    /// ```
+    fn mul_assign(&mut self, _rhs: T) {
+        *self = *self * _rhs;
+    }
 }
 
 impl<T: Div<Output = T>> Div for Vec2<T> {
@@ -311,6 +708,59 @@ impl<T: Div<Output = T> + Copy> Vec2<T> {
     }
 }
 
+impl<T: Div<Output = T> + Copy> Div<T> for Vec2<T> {
+    type Output = Self;
+
+    /// div performs scalar division on a vector, it is identical to
+    /// div_scalar. Integer components are truncated as usual.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::Vec2;
+    ///
+    /// assert_eq!(Vec2(3, 5) / 2, Vec2(1, 2));
+    /// assert_eq!(Vec2(2.0, 4.0) / 2.0, Vec2(1.0, 2.0));
+    /// ```
+    fn div(self, _rhs: T) -> Self {
+        Vec2(self.0 / _rhs, self.1 / _rhs)
+    }
+}
+
+impl<T: Div<Output = T> + Copy> DivAssign for Vec2<T> {
+    /// div_assign performs in-place component-wise division of two vectors.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::Vec2;
+    ///
+    /// let mut a = Vec2(4, 5);
+    /// a /= Vec2(1, 2);
+    /// assert_eq!(a, Vec2(4, 2));
+    /// ```
+    fn div_assign(&mut self, _rhs: Self) {
+        *self = *self / _rhs;
+    }
+}
+
+impl<T: Div<Output = T> + Copy> DivAssign<T> for Vec2<T> {
+    /// div_assign performs in-place scalar division on a vector.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::Vec2;
+    ///
+    /// let mut a = Vec2(2.0, 4.0);
+    /// a /= 2.0;
+    /// assert_eq!(a, Vec2(1.0, 2.0));
+    /// ```
+    fn div_assign(&mut self, _rhs: T) {
+        *self = *self / _rhs;
+    }
+}
+
 impl<T: Clamp<Elem = T> + Copy> Clamp for Vec2<T> {
     type Elem = T;
 
@@ -395,6 +845,32 @@ impl<T: PartialEq> PartialEq for Vec2<T> {
     }
 }
 
+impl<T: Eq> Eq for Vec2<T> {}
+
+impl<T: Hash> Hash for Vec2<T> {
+    /// hash feeds each component of the vector, in order, into the given
+    /// hasher. Floating-point vectors do not implement Hash, as their
+    /// components do not.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::HashSet;
+    /// use fiz_math::Vec2;
+    ///
+    /// let mut voxels = HashSet::new();
+    /// voxels.insert(Vec2(1, 2));
+    /// voxels.insert(Vec2(4, 5));
+    /// voxels.insert(Vec2(1, 2));
+    /// assert_eq!(voxels.len(), 2);
+    /// assert!(voxels.contains(&Vec2(4, 5)));
+    /// ```
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.hash(state);
+        self.1.hash(state);
+    }
+}
+
 impl<T: PartialOrd> PartialOrd for Vec2<T> {
     /// partial_cmp compares the two vectors component-wise.
     ///
@@ -465,6 +941,24 @@ impl<T: PartialOrd> Vec2<T> {
                  other.1
              })
     }
+
+    /// clamp_vec returns the vector with each component clamped to the range
+    /// of the corresponding components in the `min` and `max` vectors. If a
+    /// component of `min` is greater than the one in `max`, then the `max`
+    /// component is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::Vec2;
+    ///
+    /// let a = Vec2(-1, 5);
+    /// assert_eq!(a.clamp_vec(Vec2(0, 0), Vec2(4, 4)), Vec2(0, 4));
+    /// assert_eq!(a.clamp_vec(Vec2(5, 5), Vec2(3, 3)), Vec2(3, 3));
+    /// ```
+    pub fn clamp_vec(self, min: Self, max: Self) -> Self {
+        self.max(min).min(max)
+    }
 }
 
 impl<T: Zero> Zero for Vec2<T> {
@@ -549,6 +1043,21 @@ impl<T: Float> Vec2<T> {
         self.length_sq().sqrt()
     }
 
+    /// distance returns the distance between the `self` and `other` vectors.
+    /// Use distance_sq instead when comparing distances, because it avoids the
+    /// extra sqrt operation needed by this method.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::Vec2;
+    ///
+    /// assert_eq!(Vec2(0.0, 0.0).distance(Vec2(3.0, 4.0)), 5.0);
+    /// ```
+    pub fn distance(self, other: Self) -> T {
+        (other - self).length()
+    }
+
     /// normalize returns the normalized (i.e. length/magnitude == 1) vector
     /// representing self. If the vector's length is zero and division by zero
     /// would occur, then None is returned.
@@ -586,6 +1095,24 @@ impl<T: Float> Vec2<T> {
         other.mul_scalar(self.dot(other) / other.length_sq())
     }
 
+    /// reject returns a vector representing the rejection of the `self` vector
+    /// from the `other` vector, that is the component of `self` perpendicular
+    /// to `other`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::Vec2;
+    ///
+    /// let a = Vec2(1.0f64, 2.0);
+    /// let b = Vec2(1.0, 3.0);
+    /// assert!(a.reject(b).dot(b).abs() < 1e-10);
+    /// assert!((a.project(b) + a.reject(b)).almost_equal(a, 1e-10));
+    /// ```
+    pub fn reject(self, other: Self) -> Self {
+        self - self.project(other)
+    }
+
     /// lerp returns a vector representing the linear interpolation between the
     /// `self` and `other` vectors. The parameter `t` is the amount to interpolate
     /// between the vectors (e.g. `0.0 - 1.0`).
@@ -597,10 +1124,33 @@ impl<T: Float> Vec2<T> {
     ///
     /// let a = Vec2(1.25, 1.25);
     /// let b = Vec2(2.0, 2.0);
-    /// assert_eq!(a.lerp(b, 0.25), Vec2(0.625, 0.625));
+    /// assert_eq!(a.lerp(b, 0.0), a);
+    /// assert_eq!(a.lerp(b, 0.25), Vec2(1.4375, 1.4375));
+    /// assert_eq!(a.lerp(b, 0.5), Vec2(1.625, 1.625));
+    /// assert_eq!(a.lerp(b, 1.0), b);
     /// ```
     pub fn lerp(self, other: Self, t: T) -> Self {
-        self * other.mul_scalar(t)
+        Vec2(self.0.lerp(other.0, t), self.1.lerp(other.1, t))
+    }
+}
+
+impl<T: Num + PartialOrd + Copy> Vec2<T> {
+    /// distance_sq returns the distance squared between the `self` and `other`
+    /// vectors, useful primarily for comparing distances. The difference of each
+    /// component is taken such that unsigned vectors do not underflow.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::Vec2;
+    ///
+    /// assert_eq!(Vec2(1, 2).distance_sq(Vec2(4, 6)), 25);
+    /// assert_eq!(Vec2(0.0, 0.0).distance_sq(Vec2(3.0, 4.0)), 25.0);
+    /// assert_eq!(Vec2(2u32, 0).distance_sq(Vec2(1, 0)), 1);
+    /// ```
+    pub fn distance_sq(self, other: Self) -> T {
+        let d = |a: T, b: T| if a > b { a - b } else { b - a };
+        Vec2(d(self.0, other.0), d(self.1, other.1)).length_sq()
     }
 }
 
@@ -1,15 +1,18 @@
 #![allow(dead_code)]
 
-use std::ops::{Add, Sub, Neg, Mul, Div};
-use std::cmp::{PartialEq, PartialOrd, Ordering};
+use std::ops::{Add, Sub, Neg, Mul, Div, AddAssign, SubAssign, MulAssign, DivAssign,
+               Index, IndexMut};
+use std::cmp::{PartialEq, PartialOrd, Ord, Ordering};
 pub use num::{Zero, One, Num};
 use num;
 use super::float::Float;
-use super::Vec2;
+use super::{Vec2, Vec4};
 use super::unit::ToRad;
 use std::fmt;
+use std::hash::{Hash, Hasher};
 use clamp::Clamp;
-use std::iter::IntoIterator;
+use std::iter::{IntoIterator, FromIterator, Sum, Product};
+use std::slice;
 
 /// Vec3 is a generic three-component (3D) vector type.
 ///
@@ -23,6 +26,7 @@ use std::iter::IntoIterator;
 /// ```
 /// let x = fiz_math::Vec3(1u8, 5u8, 2u8);
 /// println!("{:?}", x);
+/// assert_eq!(format!("{:?}", x), "Vec3(1, 5, 2)");
 /// ```
 ///
 /// ```
@@ -33,12 +37,96 @@ use std::iter::IntoIterator;
 /// let y = Vec3(MM(1.0), MM(5.1), MM(1.9));
 /// assert!(x.almost_equal(y, 0.1));
 /// ```
-#[derive(Copy, Clone, Debug)]
+///
+/// ```
+/// use fiz_math::Vec3;
+///
+/// let v = Vec3(1, 2, 3);
+/// assert_eq!((v.x(), v.y(), v.z()), (1, 2, 3));
+/// assert_eq!((v.0, v.1, v.2), (1, 2, 3));
+/// ```
+///
+/// GLSL-style swizzles extract and reorder components:
+///
+/// ```
+/// use fiz_math::{Vec2, Vec3};
+///
+/// let v = Vec3(1, 2, 3);
+/// assert_eq!(v.xz(), Vec2(1, 3));
+/// assert_eq!(v.zy(), Vec2(3, 2));
+/// assert_eq!(v.zyx(), Vec3(3, 2, 1));
+/// ```
+///
+/// Vectors of the primitive numeric types can be converted with `as` casts
+/// (also see the checked cast method):
+///
+/// ```
+/// use fiz_math::Vec3;
+///
+/// assert_eq!(Vec3(1i32, 2, 3).as_f32(), Vec3(1.0, 2.0, 3.0));
+/// assert_eq!(Vec3(1.7f64, -2.5, 300.0).as_i32(), Vec3(1, -2, 300));
+/// assert_eq!(Vec3(1.7f64, -2.5, 300.0).as_u8(), Vec3(1, 0, 255));
+/// assert_eq!(Vec3(1u8, 2, 3).as_usize(), Vec3(1, 2, 3));
+/// ```
+///
+/// ```
+/// use fiz_math::Vec3;
+///
+/// assert_eq!(Vec3::<f32>::default(), Vec3(0.0, 0.0, 0.0));
+/// ```
+///
+/// The components are laid out in memory as x, y, z with no padding, such
+/// that vectors can be passed to C or the GPU directly:
+///
+/// ```
+/// use std::mem::{size_of, offset_of};
+/// use fiz_math::Vec3;
+///
+/// assert_eq!(size_of::<Vec3<f32>>(), 12);
+/// assert_eq!(offset_of!(Vec3<f32>, 0), 0);
+/// assert_eq!(offset_of!(Vec3<f32>, 1), 4);
+/// assert_eq!(offset_of!(Vec3<f32>, 2), 8);
+/// ```
+#[derive(Copy, Clone, Debug, Default)]
+#[repr(C)]
 pub struct Vec3<T>(pub T, pub T, pub T);
 
+/// Axis is one of the three axes of a Vec3, used to access its components in a
+/// more type-safe way than indexing.
+///
+/// # Examples
+///
+/// ```
+/// use fiz_math::{Axis, Vec3};
+///
+/// let mut v = Vec3(1, 2, 3);
+/// assert_eq!(v.get_axis(Axis::Y), 2);
+/// v.set_axis(Axis::Z, 9);
+/// assert_eq!(v, Vec3(1, 2, 9));
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Axis {
+    X,
+    Y,
+    Z,
+}
+
 impl<T: Copy> IntoIterator for Vec3<T> {
     type Item = T;
     type IntoIter = Vec3Iterator<T>;
+
+    /// into_iter returns an iterator over the components of the vector, in
+    /// order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::Vec3;
+    ///
+    /// let v = Vec3(1, 2, 3);
+    /// assert_eq!(v.into_iter().collect::<Vec<_>>(), vec![1, 2, 3]);
+    /// assert_eq!(v.into_iter().sum::<i32>(), 6);
+    /// ```
     fn into_iter(self) -> Self::IntoIter {
         Vec3Iterator {
             v: self,
@@ -47,6 +135,7 @@ impl<T: Copy> IntoIterator for Vec3<T> {
     }
 }
 
+/// Vec3Iterator is an iterator over the components of a Vec3.
 pub struct Vec3Iterator<T> {
     v: Vec3<T>,
     index: usize,
@@ -66,17 +155,386 @@ impl<T: Copy> Iterator for Vec3Iterator<T> {
     }
 }
 
+impl<T> FromIterator<T> for Vec3<T> {
+    /// from_iter returns a vector whose components are the first three items
+    /// of the iterator, in order. Any further items are not consumed. It
+    /// panics if the iterator yields fewer than three items.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::Vec3;
+    ///
+    /// assert_eq!((1..).take(3).collect::<Vec3<i32>>(), Vec3(1, 2, 3));
+    /// assert_eq!((1..).map(|x| x * 2).collect::<Vec3<i32>>(), Vec3(2, 4, 6));
+    /// ```
+    ///
+    /// ```should_panic
+    /// use fiz_math::Vec3;
+    ///
+    /// let v: Vec3<i32> = vec![1, 2].into_iter().collect();
+    /// ```
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut iter = iter.into_iter();
+        let mut next = || iter.next().expect("Vec3 from_iter: fewer than 3 items");
+        Vec3(next(), next(), next())
+    }
+}
+
+impl<T> Index<usize> for Vec3<T> {
+    type Output = T;
+
+    /// index returns a reference to the component at the given index, i.e.
+    /// `v[0]` is the x component. It panics if the index is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::Vec3;
+    ///
+    /// let v = Vec3(1, 2, 3);
+    /// assert_eq!(v[1], 2);
+    /// ```
+    fn index(&self, i: usize) -> &T {
+        match i {
+            0 => &self.0,
+            1 => &self.1,
+            2 => &self.2,
+            _ => panic!("Vec3 index out of bounds: the len is 3 but the index is {}", i),
+        }
+    }
+}
+
+impl<T> IndexMut<usize> for Vec3<T> {
+    /// index_mut returns a mutable reference to the component at the given
+    /// index. It panics if the index is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::Vec3;
+    ///
+    /// let mut v = Vec3(1, 2, 3);
+    /// v[2] = 9;
+    /// assert_eq!(v[2], 9);
+    /// ```
+    fn index_mut(&mut self, i: usize) -> &mut T {
+        match i {
+            0 => &mut self.0,
+            1 => &mut self.1,
+            2 => &mut self.2,
+            _ => panic!("Vec3 index out of bounds: the len is 3 but the index is {}", i),
+        }
+    }
+}
+
+impl<T> From<(T, T, T)> for Vec3<T> {
+    /// from converts a tuple into a vector.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::Vec3;
+    ///
+    /// let v: Vec3<f32> = (1.0, 2.0, 3.0).into();
+    /// assert_eq!(v, Vec3(1.0, 2.0, 3.0));
+    /// ```
+    fn from(v: (T, T, T)) -> Self {
+        Vec3(v.0, v.1, v.2)
+    }
+}
+
+impl<T> From<Vec3<T>> for (T, T, T) {
+    /// from converts a vector into a tuple.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::Vec3;
+    ///
+    /// let t: (f32, f32, f32) = Vec3(1.0, 2.0, 3.0).into();
+    /// assert_eq!(t, (1.0, 2.0, 3.0));
+    /// ```
+    fn from(v: Vec3<T>) -> Self {
+        (v.0, v.1, v.2)
+    }
+}
+
+impl<T> From<[T; 3]> for Vec3<T> {
+    /// from converts an array into a vector.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::Vec3;
+    ///
+    /// let v: Vec3<f32> = [1.0, 2.0, 3.0].into();
+    /// assert_eq!(v, Vec3(1.0, 2.0, 3.0));
+    /// ```
+    fn from(v: [T; 3]) -> Self {
+        let [x, y, z] = v;
+        Vec3(x, y, z)
+    }
+}
+
+impl<T> From<Vec3<T>> for [T; 3] {
+    /// from converts a vector into an array.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::Vec3;
+    ///
+    /// let a: [f32; 3] = Vec3::from([1.0, 2.0, 3.0]).into();
+    /// assert_eq!(a, [1.0, 2.0, 3.0]);
+    /// ```
+    fn from(v: Vec3<T>) -> Self {
+        [v.0, v.1, v.2]
+    }
+}
+
+impl<T> Vec3<T> {
+    /// new returns a vector with the given components. It is identical to
+    /// `Vec3(x, y, z)`, but may also be used to define constants.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::Vec3;
+    ///
+    /// const UP: Vec3<f32> = Vec3::new(0.0, 1.0, 0.0);
+    ///
+    /// assert_eq!(UP, Vec3(0.0, 1.0, 0.0));
+    /// assert_eq!(Vec3::new(1, 2, 3), Vec3(1, 2, 3));
+    /// ```
+    pub const fn new(x: T, y: T, z: T) -> Self {
+        Vec3(x, y, z)
+    }
+
+    /// map returns a vector whose components are the result of applying the
+    /// function `f` to each component of this vector. The component type may
+    /// change.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::Vec3;
+    ///
+    /// assert_eq!(Vec3(1.0, 2.0, 3.0).map(|c| c as i32), Vec3(1, 2, 3));
+    /// ```
+    pub fn map<U, F: Fn(T) -> U>(self, f: F) -> Vec3<U> {
+        Vec3(f(self.0), f(self.1), f(self.2))
+    }
+
+    /// zip returns a vector whose components are the result of applying the
+    /// function `f` to each pair of components from this vector and the other
+    /// one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::Vec3;
+    ///
+    /// let a = Vec3(1.0f32, 5.0, 3.0);
+    /// let b = Vec3(4.0, 2.0, 6.0);
+    /// assert_eq!(a.zip(b, |x, y| x.max(y)), Vec3(4.0, 5.0, 6.0));
+    /// assert_eq!(a.zip(b, |x, y| x.min(y)), Vec3(1.0, 2.0, 3.0));
+    /// ```
+    pub fn zip<U, V, F: Fn(T, U) -> V>(self, other: Vec3<U>, f: F) -> Vec3<V> {
+        Vec3(f(self.0, other.0), f(self.1, other.1), f(self.2, other.2))
+    }
+
+    /// select returns a vector whose components are taken from `if_true`
+    /// where the mask is true, and from `if_false` where it is false.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::Vec3;
+    ///
+    /// let mask = Vec3(true, false, true);
+    /// assert_eq!(Vec3::select(mask, Vec3(1, 2, 3), Vec3(4, 5, 6)), Vec3(1, 5, 3));
+    ///
+    /// // Branchless component-wise minimum.
+    /// let (a, b) = (Vec3(1.0, 5.0, 3.0), Vec3(4.0, 2.0, 6.0));
+    /// assert_eq!(Vec3::select(a.cmplt(b), a, b), Vec3(1.0, 2.0, 3.0));
+    /// ```
+    pub fn select(mask: Vec3<bool>, if_true: Self, if_false: Self) -> Self {
+        let Vec3(t0, t1, t2) = if_true;
+        let Vec3(f0, f1, f2) = if_false;
+        Vec3(if mask.0 { t0 } else { f0 },
+             if mask.1 { t1 } else { f1 },
+             if mask.2 { t2 } else { f2 })
+    }
+
+    /// extend returns a four-component vector with the components of this
+    /// vector and the given `w` component.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::{Vec3, Vec4};
+    ///
+    /// assert_eq!(Vec3(1, 2, 3).extend(4), Vec4(1, 2, 3, 4));
+    /// ```
+    pub fn extend(self, w: T) -> Vec4<T> {
+        Vec4(self.0, self.1, self.2, w)
+    }
+
+    /// truncate returns a two-component vector with the z component of this
+    /// vector dropped. Also see the xy method.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::{Vec2, Vec3};
+    ///
+    /// let v = Vec3(1, 2, 3);
+    /// assert_eq!(v.truncate(), Vec2(1, 2));
+    /// assert_eq!(v.truncate().extend(v.z()), v);
+    /// ```
+    pub fn truncate(self) -> Vec2<T> {
+        Vec2(self.0, self.1)
+    }
+
+    /// get_axis returns the component of the vector along the given axis.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::{Axis, Vec3};
+    ///
+    /// let v = Vec3(1, 2, 3);
+    /// assert_eq!(v.get_axis(Axis::X), 1);
+    /// assert_eq!(v.get_axis(Axis::Y), 2);
+    /// assert_eq!(v.get_axis(Axis::Z), 3);
+    /// ```
+    pub fn get_axis(self, axis: Axis) -> T {
+        match axis {
+            Axis::X => self.0,
+            Axis::Y => self.1,
+            Axis::Z => self.2,
+        }
+    }
+
+    /// set_axis sets the component of the vector along the given axis.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::{Axis, Vec3};
+    ///
+    /// let mut v = Vec3(1, 2, 3);
+    /// v.set_axis(Axis::Y, 9);
+    /// assert_eq!(v, Vec3(1, 9, 3));
+    /// ```
+    pub fn set_axis(&mut self, axis: Axis, v: T) {
+        match axis {
+            Axis::X => self.0 = v,
+            Axis::Y => self.1 = v,
+            Axis::Z => self.2 = v,
+        }
+    }
+
+    /// with_x returns a copy of this vector with the x component replaced.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::Vec3;
+    ///
+    /// assert_eq!(Vec3(1, 2, 3).with_x(9), Vec3(9, 2, 3));
+    /// ```
+    pub fn with_x(self, x: T) -> Self {
+        Vec3(x, self.1, self.2)
+    }
+
+    /// with_y returns a copy of this vector with the y component replaced.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::Vec3;
+    ///
+    /// assert_eq!(Vec3(1, 2, 3).with_y(9), Vec3(1, 9, 3));
+    /// ```
+    pub fn with_y(self, y: T) -> Self {
+        Vec3(self.0, y, self.2)
+    }
+
+    /// with_z returns a copy of this vector with the z component replaced,
+    /// e.g. to flatten a point onto the xy plane.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::Vec3;
+    ///
+    /// assert_eq!(Vec3(1.0, 2.0, 3.0).with_z(0.0), Vec3(1.0, 2.0, 0.0));
+    /// ```
+    pub fn with_z(self, z: T) -> Self {
+        Vec3(self.0, self.1, z)
+    }
+}
+
+impl<T: Copy> Vec3<T> {
+    /// splat returns a vector with every component set to `v`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::Vec3;
+    ///
+    /// assert_eq!(Vec3::splat(5), Vec3(5, 5, 5));
+    /// ```
+    pub fn splat(v: T) -> Self {
+        Vec3(v, v, v)
+    }
+
+    /// from_slice returns a vector with the first three elements of the given
+    /// slice as its components, or None if the slice has fewer than three
+    /// elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::Vec3;
+    ///
+    /// assert_eq!(Vec3::from_slice(&[1, 2, 3]), Some(Vec3(1, 2, 3)));
+    /// assert_eq!(Vec3::from_slice(&[1, 2, 3, 4]), Some(Vec3(1, 2, 3)));
+    /// assert_eq!(Vec3::from_slice(&[1, 2]), None);
+    /// ```
+    pub fn from_slice(s: &[T]) -> Option<Self> {
+        if s.len() < 3 {
+            None
+        } else {
+            Some(Vec3(s[0], s[1], s[2]))
+        }
+    }
+}
+
 impl<T: fmt::Display> fmt::Display for Vec3<T> {
-    /// fmt formats the vector.
+    /// fmt formats the vector. A precision, if given, is applied to each
+    /// component.
     ///
     /// # Examples
     ///
     /// ```
     /// let x = fiz_math::Vec3(1u8, 5u8, 2u8);
     /// assert_eq!(format!("{}", x), "Vec3(1, 5, 2)");
+    /// assert_eq!(format!("{}", fiz_math::Vec3(1.0, 2.0, 3.0)), "Vec3(1, 2, 3)");
+    /// ```
+    ///
+    /// ```
+    /// let x = fiz_math::Vec3(1.0 / 3.0, 2.0, 3.0);
+    /// assert_eq!(format!("{:.2}", x), "Vec3(0.33, 2.00, 3.00)");
+    /// assert_eq!(format!("{:.0}", x), "Vec3(0, 2, 3)");
     /// ```
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "Vec3({}, {}, {})", self.0, self.1, self.2)
+        match f.precision() {
+            Some(p) => write!(f, "Vec3({:.*}, {:.*}, {:.*})", p, self.0, p, self.1, p, self.2),
+            None => write!(f, "Vec3({}, {}, {})", self.0, self.1, self.2),
+        }
     }
 }
 
@@ -102,31 +560,124 @@ impl<T: One> One for Vec3<T> {
     }
 }
 
-impl<T: Float> Vec3<T> {
-    /// almost_equal tells if this vector is equal to the other given an absolute
-    /// tolerence value (see the almost_equal function for more details).
+impl<T: One> Product for Vec3<T> {
+    /// product returns the component-wise product of all the vectors in the
+    /// iterator, or the one vector if it is empty.
     ///
     /// # Examples
     ///
     /// ```
     /// use fiz_math::Vec3;
     ///
-    /// let a = Vec3::<f32>(1.0, 1.0, 1.0);
-    /// let b = Vec3::<f32>(0.9, 0.9, 0.9);
-    /// assert!(a.almost_equal(b, 0.1000001));
-    /// assert!(!a.almost_equal(b, 0.1));
+    /// let scales = vec![Vec3(2.0, 1.0, 0.5), Vec3(3.0, 2.0, 4.0)];
+    /// assert_eq!(scales.into_iter().product::<Vec3<f32>>(), Vec3(6.0, 2.0, 2.0));
+    /// assert_eq!(Vec::new().into_iter().product::<Vec3<i32>>(), Vec3(1, 1, 1));
     /// ```
-    pub fn almost_equal<N: num::Float>(self, other: Self, abs_tol: N) -> bool {
-        self.0.almost_equal(other.0, abs_tol) && self.1.almost_equal(other.1, abs_tol) &&
-        self.2.almost_equal(other.2, abs_tol)
+    fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::one(), |a, b| a * b)
     }
+}
 
-    /// is_nan tells if all of this vectors components are NaN.
+impl<T: Zero + One> Vec3<T> {
+    /// unit_x returns the unit vector along the x axis.
     ///
     /// # Examples
     ///
     /// ```
-    /// # extern crate num;
+    /// use fiz_math::Vec3;
+    ///
+    /// assert_eq!(Vec3::<f32>::unit_x(), Vec3(1.0, 0.0, 0.0));
+    /// ```
+    pub fn unit_x() -> Self {
+        Vec3(T::one(), T::zero(), T::zero())
+    }
+
+    /// unit_y returns the unit vector along the y axis.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::Vec3;
+    ///
+    /// assert_eq!(Vec3::<f32>::unit_y(), Vec3(0.0, 1.0, 0.0));
+    /// ```
+    pub fn unit_y() -> Self {
+        Vec3(T::zero(), T::one(), T::zero())
+    }
+
+    /// unit_z returns the unit vector along the z axis.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::Vec3;
+    ///
+    /// assert_eq!(Vec3::<f32>::unit_z(), Vec3(0.0, 0.0, 1.0));
+    /// ```
+    pub fn unit_z() -> Self {
+        Vec3(T::zero(), T::zero(), T::one())
+    }
+}
+
+impl<T: Float> Vec3<T> {
+    /// almost_equal tells if this vector is equal to the other given an absolute
+    /// tolerence value (see the almost_equal function for more details).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::Vec3;
+    ///
+    /// let a = Vec3::<f32>(1.0, 1.0, 1.0);
+    /// let b = Vec3::<f32>(0.9, 0.9, 0.9);
+    /// assert!(a.almost_equal(b, 0.1000001));
+    /// assert!(!a.almost_equal(b, 0.1));
+    /// ```
+    pub fn almost_equal<N: num::Float>(self, other: Self, abs_tol: N) -> bool {
+        almost_equal_all!(abs_tol; (self.0, other.0), (self.1, other.1), (self.2, other.2))
+    }
+
+    /// approx_eq is short-hand for `self.almost_equal(other, fiz_math::EPSILON)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::Vec3;
+    ///
+    /// let a = Vec3(1.0, 1.0, 1.0);
+    /// assert!(a.approx_eq(Vec3(1.000000001, 1.0, 0.999999999)));
+    /// assert!(!a.approx_eq(Vec3(0.9, 0.9, 0.9)));
+    /// ```
+    pub fn approx_eq(self, other: Self) -> bool {
+        self.0.equal(other.0) && self.1.equal(other.1) && self.2.equal(other.2)
+    }
+
+    /// almost_equal_rel tells if this vector is equal to the other given an
+    /// absolute and a relative tolerence value (see the almost_equal_rel
+    /// function for more details).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::Vec3;
+    ///
+    /// let a = Vec3(1.0e20, 1.0e20, 1.0e20);
+    /// let b = Vec3(1.0e20, 1.0e20, 1.0000001e20);
+    /// assert!(!a.almost_equal(b, 1e-8));
+    /// assert!(a.almost_equal_rel(b, 1e-8, 1e-6));
+    /// ```
+    pub fn almost_equal_rel<N: num::Float>(self, other: Self, abs_tol: N, rel_tol: N) -> bool {
+        self.0.almost_equal_rel(other.0, abs_tol, rel_tol) &&
+        self.1.almost_equal_rel(other.1, abs_tol, rel_tol) &&
+        self.2.almost_equal_rel(other.2, abs_tol, rel_tol)
+    }
+
+    /// is_nan tells if any of this vectors components are NaN.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # extern crate num;
     /// # extern crate fiz_math;
     /// use num::traits::Float;
     /// use fiz_math::Vec3;
@@ -134,25 +685,432 @@ impl<T: Float> Vec3<T> {
     /// # fn main() {
     /// let n:f32 = Float::nan();
     /// assert!(Vec3(n, n, n).is_nan());
-    /// assert!(!Vec3(n, 0.0, 0.0).is_nan());
+    /// assert!(Vec3(n, 0.0, 0.0).is_nan());
+    /// assert!(!Vec3(0.0, 0.0, 0.0).is_nan());
     /// # }
     /// ```
     pub fn is_nan(self) -> bool {
-        self.0.is_nan() && self.1.is_nan() && self.2.is_nan()
+        self.0.is_nan() || self.1.is_nan() || self.2.is_nan()
+    }
+
+    /// is_finite tells if all of this vectors components are finite, i.e.
+    /// neither infinite nor NaN.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::Vec3;
+    ///
+    /// assert!(Vec3(1.0, 2.0, 3.0).is_finite());
+    /// assert!(!Vec3(1.0, std::f32::INFINITY, 3.0).is_finite());
+    /// assert!(!Vec3(1.0, 2.0, std::f32::NAN).is_finite());
+    /// ```
+    pub fn is_finite(self) -> bool {
+        self.0.is_finite() && self.1.is_finite() && self.2.is_finite()
+    }
+
+    /// round returns the nearest integer to a number. Round half-way cases away
+    /// from 0.0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::Vec3;
+    ///
+    /// assert_eq!(Vec3(0.3, 1.3, 2.0).round(), Vec3(0.0, 1.0, 2.0))
+    /// ```
+    pub fn round(&self) -> Self {
+        Vec3(self.0.round(), self.1.round(), self.2.round())
+    }
+
+    /// floor returns the vector with each component rounded down to the
+    /// nearest integer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::Vec3;
+    ///
+    /// assert_eq!(Vec3(1.4, 2.6, 3.5).floor(), Vec3(1.0, 2.0, 3.0))
+    /// ```
+    pub fn floor(&self) -> Self {
+        Vec3(self.0.floor(), self.1.floor(), self.2.floor())
+    }
+
+    /// ceil returns the vector with each component rounded up to the nearest
+    /// integer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::Vec3;
+    ///
+    /// assert_eq!(Vec3(1.4, 2.6, 3.5).ceil(), Vec3(2.0, 3.0, 4.0))
+    /// ```
+    pub fn ceil(&self) -> Self {
+        Vec3(self.0.ceil(), self.1.ceil(), self.2.ceil())
+    }
+
+    /// to_degrees converts each component of the vector from radians to
+    /// degrees, e.g. for a triple of Euler angles.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::f64::consts::PI;
+    /// use fiz_math::Vec3;
+    ///
+    /// let d = Vec3(PI, PI / 2.0, 0.0).to_degrees();
+    /// assert!(d.almost_equal(Vec3(180.0, 90.0, 0.0), 1e-10));
+    /// ```
+    pub fn to_degrees(self) -> Self {
+        Vec3(self.0.to_degrees(), self.1.to_degrees(), self.2.to_degrees())
+    }
+
+    /// to_radians converts each component of the vector from degrees to
+    /// radians, e.g. for a triple of Euler angles.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::f64::consts::PI;
+    /// use fiz_math::Vec3;
+    ///
+    /// let r = Vec3(180.0, 90.0, 0.0).to_radians();
+    /// assert!(r.almost_equal(Vec3(PI, PI / 2.0, 0.0), 1e-10));
+    /// ```
+    pub fn to_radians(self) -> Self {
+        Vec3(self.0.to_radians(), self.1.to_radians(), self.2.to_radians())
+    }
+
+    /// recip returns the vector with the reciprocal (i.e. `1/x`) of each
+    /// component. Zero components produce infinities.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::Vec3;
+    ///
+    /// assert_eq!(Vec3(2.0, 4.0, 0.5).recip(), Vec3(0.5, 0.25, 2.0));
+    /// assert_eq!(Vec3(0.0, -0.0, 1.0).recip(),
+    ///            Vec3(std::f64::INFINITY, std::f64::NEG_INFINITY, 1.0));
+    /// ```
+    pub fn recip(self) -> Self {
+        Vec3(self.0.recip(), self.1.recip(), self.2.recip())
+    }
+
+    /// powf returns the vector with each component raised to the floating
+    /// point power `n`, e.g. for per-channel gamma correction.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::Vec3;
+    ///
+    /// assert_eq!(Vec3(4.0, 9.0, 16.0).powf(0.5), Vec3(2.0, 3.0, 4.0));
+    /// ```
+    pub fn powf(self, n: T) -> Self {
+        Vec3(self.0.powf(n), self.1.powf(n), self.2.powf(n))
+    }
+
+    /// powi returns the vector with each component raised to the integer power
+    /// `n`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::Vec3;
+    ///
+    /// assert_eq!(Vec3(2.0, 3.0, 4.0).powi(2), Vec3(4.0, 9.0, 16.0));
+    /// assert_eq!(Vec3(2.0, 4.0, 0.5).powi(-1), Vec3(0.5, 0.25, 2.0));
+    /// ```
+    pub fn powi(self, n: i32) -> Self {
+        Vec3(self.0.powi(n), self.1.powi(n), self.2.powi(n))
+    }
+
+    /// sqrt returns the vector with the square root of each component.
+    /// Negative components produce NaN.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::Vec3;
+    ///
+    /// assert_eq!(Vec3(4.0, 9.0, 16.0).sqrt(), Vec3(2.0, 3.0, 4.0));
+    /// assert!(Vec3(-1.0, 0.0, 0.0).sqrt().is_nan());
+    /// ```
+    pub fn sqrt(self) -> Self {
+        Vec3(self.0.sqrt(), self.1.sqrt(), self.2.sqrt())
+    }
+
+    /// exp returns the vector with `e^x` of each component.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::f64::consts::E;
+    /// use fiz_math::Vec3;
+    ///
+    /// assert_eq!(Vec3(0.0, 1.0, 0.0).exp(), Vec3(1.0, E, 1.0));
+    /// ```
+    pub fn exp(self) -> Self {
+        Vec3(self.0.exp(), self.1.exp(), self.2.exp())
+    }
+
+    /// ln returns the vector with the natural logarithm of each component.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::f64::consts::E;
+    /// use fiz_math::Vec3;
+    ///
+    /// assert_eq!(Vec3(1.0, E, 1.0).ln(), Vec3(0.0, 1.0, 0.0));
+    /// ```
+    pub fn ln(self) -> Self {
+        Vec3(self.0.ln(), self.1.ln(), self.2.ln())
+    }
+
+    /// sin returns the vector with the sine of each component (in radians).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::f64::consts::FRAC_PI_2;
+    /// use fiz_math::Vec3;
+    ///
+    /// assert_eq!(Vec3(0.0, FRAC_PI_2, -FRAC_PI_2).sin(), Vec3(0.0, 1.0, -1.0));
+    /// ```
+    pub fn sin(self) -> Self {
+        Vec3(self.0.sin(), self.1.sin(), self.2.sin())
+    }
+
+    /// cos returns the vector with the cosine of each component (in radians).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::f64::consts::PI;
+    /// use fiz_math::Vec3;
+    ///
+    /// assert_eq!(Vec3(0.0, PI, 0.0).cos(), Vec3(1.0, -1.0, 1.0));
+    /// ```
+    pub fn cos(self) -> Self {
+        Vec3(self.0.cos(), self.1.cos(), self.2.cos())
+    }
+
+    /// copysign returns the vector with the magnitude of each component of
+    /// `self` and the sign of the corresponding component of `sign`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::Vec3;
+    ///
+    /// let v = Vec3(3.0, 4.0, 5.0).copysign(Vec3(-1.0, 1.0, -1.0));
+    /// assert_eq!(v, Vec3(-3.0, 4.0, -5.0));
+    /// assert_eq!(Vec3(-3.0, -4.0, 5.0).copysign(Vec3(0.0, -0.0, 2.0)), Vec3(3.0, -4.0, 5.0));
+    /// ```
+    pub fn copysign(self, sign: Self) -> Self {
+        Vec3(self.0.copysign(sign.0), self.1.copysign(sign.1), self.2.copysign(sign.2))
+    }
+
+    /// mul_add returns `self * mul + add` computed component-wise with a
+    /// fused multiply-add, i.e. with only a single rounding error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::Vec3;
+    ///
+    /// let v = Vec3(1.0, 2.0, 3.0).mul_add(Vec3(4.0, 5.0, 6.0), Vec3(0.5, 0.5, 0.5));
+    /// assert_eq!(v, Vec3(4.5, 10.5, 18.5));
+    /// ```
+    pub fn mul_add(self, mul: Self, add: Self) -> Self {
+        Vec3(self.0.mul_add(mul.0, add.0),
+             self.1.mul_add(mul.1, add.1),
+             self.2.mul_add(mul.2, add.2))
+    }
+}
+
+impl<T: num::Signed> Vec3<T> {
+    /// abs returns the vector with the absolute value of each component.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::Vec3;
+    ///
+    /// assert_eq!(Vec3(-1.0, 2.0, -3.0).abs(), Vec3(1.0, 2.0, 3.0));
+    /// assert_eq!(Vec3(-1, 2, -3).abs(), Vec3(1, 2, 3));
+    /// ```
+    pub fn abs(&self) -> Self {
+        Vec3(self.0.abs(), self.1.abs(), self.2.abs())
+    }
+
+    /// signum returns the vector with the sign of each component, i.e. -1, 0
+    /// or 1 for integers.
+    ///
+    /// For floats the sign of zero is taken into account, such that `0.0` is
+    /// 1.0 and `-0.0` is -1.0, while NaN components remain NaN.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::Vec3;
+    ///
+    /// assert_eq!(Vec3(-3, 0, 5).signum(), Vec3(-1, 0, 1));
+    /// assert_eq!(Vec3(-3.0, 0.5, 5.0).signum(), Vec3(-1.0, 1.0, 1.0));
+    /// assert_eq!(Vec3(0.0, -0.0, 1.0).signum(), Vec3(1.0, -1.0, 1.0));
+    /// assert!(Vec3(std::f64::NAN, 1.0, 1.0).signum().is_nan());
+    /// ```
+    pub fn signum(&self) -> Self {
+        Vec3(self.0.signum(), self.1.signum(), self.2.signum())
+    }
+}
+
+impl<T: num::Signed + PartialOrd> Vec3<T> {
+    /// largest_axis returns the axis along which the component of the vector
+    /// has the greatest absolute value. Ties are broken in favor of X, then Y,
+    /// then Z.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::{Axis, Vec3};
+    ///
+    /// assert_eq!(Vec3(1.0, -5.0, 3.0).largest_axis(), Axis::Y);
+    /// assert_eq!(Vec3(1, 2, -3).largest_axis(), Axis::Z);
+    /// assert_eq!(Vec3(2, -2, 2).largest_axis(), Axis::X);
+    /// assert_eq!(Vec3(0, 2, -2).largest_axis(), Axis::Y);
+    /// ```
+    pub fn largest_axis(&self) -> Axis {
+        let a = self.abs();
+        if a.1 > a.0 {
+            if a.2 > a.1 { Axis::Z } else { Axis::Y }
+        } else if a.2 > a.0 {
+            Axis::Z
+        } else {
+            Axis::X
+        }
+    }
+}
+
+impl<T: num::ToPrimitive> Vec3<T> {
+    /// cast converts each component of the vector into the numeric type `U`,
+    /// returning None if any of the components is out of range for it (or is
+    /// NaN when casting to an integer type). Fractional floating-point
+    /// components cast to an integer type are truncated toward zero, not
+    /// rejected.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::Vec3;
+    ///
+    /// assert_eq!(Vec3(1, 2, 3).cast::<f32>(), Some(Vec3(1.0, 2.0, 3.0)));
+    /// assert_eq!(Vec3(1.5f64, 2.0, 255.0).cast::<u8>(), Some(Vec3(1, 2, 255)));
+    /// assert_eq!(Vec3(1.9f64, -2.9, 0.5).cast::<i32>(), Some(Vec3(1, -2, 0)));
+    /// assert_eq!(Vec3(1.0f64, 300.0, 3.0).cast::<u8>(), None);
+    /// assert_eq!(Vec3(-1.0f64, 2.0, 3.0).cast::<u8>(), None);
+    /// ```
+    pub fn cast<U: num::NumCast>(self) -> Option<Vec3<U>> {
+        Some(Vec3(U::from(self.0)?, U::from(self.1)?, U::from(self.2)?))
+    }
+}
+
+impl<T: num::CheckedAdd> Vec3<T> {
+    /// checked_add performs component-wise addition of two vectors, returning
+    /// None if any of the components overflow.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::Vec3;
+    ///
+    /// assert_eq!(Vec3::<u8>(1, 2, 3).checked_add(Vec3(4, 5, 6)), Some(Vec3(5, 7, 9)));
+    /// assert_eq!(Vec3::<u8>(250, 0, 0).checked_add(Vec3(10, 0, 0)), None);
+    /// ```
+    pub fn checked_add(self, other: Self) -> Option<Self> {
+        Some(Vec3(self.0.checked_add(&other.0)?,
+                  self.1.checked_add(&other.1)?,
+                  self.2.checked_add(&other.2)?))
+    }
+}
+
+impl<T: num::CheckedSub> Vec3<T> {
+    /// checked_sub performs component-wise subtraction of two vectors,
+    /// returning None if any of the components overflow.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::Vec3;
+    ///
+    /// assert_eq!(Vec3::<u8>(4, 5, 6).checked_sub(Vec3(1, 2, 3)), Some(Vec3(3, 3, 3)));
+    /// assert_eq!(Vec3::<u8>(0, 5, 6).checked_sub(Vec3(1, 2, 3)), None);
+    /// ```
+    pub fn checked_sub(self, other: Self) -> Option<Self> {
+        Some(Vec3(self.0.checked_sub(&other.0)?,
+                  self.1.checked_sub(&other.1)?,
+                  self.2.checked_sub(&other.2)?))
+    }
+}
+
+impl<T: num::CheckedMul> Vec3<T> {
+    /// checked_mul performs component-wise multiplication of two vectors,
+    /// returning None if any of the components overflow.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::Vec3;
+    ///
+    /// assert_eq!(Vec3::<i8>(1, -2, 3).checked_mul(Vec3(4, 5, 6)), Some(Vec3(4, -10, 18)));
+    /// assert_eq!(Vec3::<i8>(1, 2, 64).checked_mul(Vec3(1, 2, 2)), None);
+    /// ```
+    pub fn checked_mul(self, other: Self) -> Option<Self> {
+        Some(Vec3(self.0.checked_mul(&other.0)?,
+                  self.1.checked_mul(&other.1)?,
+                  self.2.checked_mul(&other.2)?))
+    }
+}
+
+impl<T: num::Saturating> Vec3<T> {
+    /// saturating_add performs component-wise addition of two vectors,
+    /// clamping each component at the numeric bounds instead of overflowing.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::Vec3;
+    ///
+    /// let a = Vec3::<u8>(250, 0, 0);
+    /// assert_eq!(a.saturating_add(Vec3(10, 0, 0)), Vec3(255, 0, 0));
+    /// assert_eq!(Vec3::<i8>(-100, 0, 100).saturating_add(Vec3(-100, 1, 100)),
+    ///            Vec3(-128, 1, 127));
+    /// ```
+    pub fn saturating_add(self, other: Self) -> Self {
+        Vec3(self.0.saturating_add(other.0),
+             self.1.saturating_add(other.1),
+             self.2.saturating_add(other.2))
     }
 
-    /// round returns the nearest integer to a number. Round half-way cases away
-    /// from 0.0.
+    /// saturating_sub performs component-wise subtraction of two vectors,
+    /// clamping each component at the numeric bounds instead of overflowing.
     ///
     /// # Examples
     ///
     /// ```
     /// use fiz_math::Vec3;
     ///
-    /// assert_eq!(Vec3(0.3, 1.3, 2.0).round(), Vec3(0.0, 1.0, 2.0))
+    /// let a = Vec3::<u8>(5, 20, 255);
+    /// assert_eq!(a.saturating_sub(Vec3(10, 10, 10)), Vec3(0, 10, 245));
     /// ```
-    pub fn round(&self) -> Self {
-        Vec3(self.0.round(), self.1.round(), self.2.round())
+    pub fn saturating_sub(self, other: Self) -> Self {
+        Vec3(self.0.saturating_sub(other.0),
+             self.1.saturating_sub(other.1),
+             self.2.saturating_sub(other.2))
     }
 }
 
@@ -189,6 +1147,36 @@ impl<T: Add<Output = T> + Copy> Vec3<T> {
     pub fn add_scalar(self, _rhs: T) -> Self {
         Vec3(self.0 + _rhs, self.1 + _rhs, self.2 + _rhs)
     }
+
+    /// sum returns the sum of all of the components of the vector.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::Vec3;
+    ///
+    /// assert_eq!(Vec3(1, 2, 3).sum(), 6);
+    /// ```
+    pub fn sum(self) -> T {
+        self.0 + self.1 + self.2
+    }
+}
+
+impl<T: Add<Output = T> + Copy> AddAssign for Vec3<T> {
+    /// add_assign performs in-place component-wise addition of two vectors.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::Vec3;
+    ///
+    /// let mut a = Vec3(1, 2, 3);
+    /// a += Vec3(4, 5, 6);
+    /// assert_eq!(a, Vec3(5, 7, 9));
+    /// ```
+    fn add_assign(&mut self, _rhs: Self) {
+        *self = *self + _rhs;
+    }
 }
 
 impl<T: Neg<Output = T>> Neg for Vec3<T> {
@@ -202,6 +1190,8 @@ impl<T: Neg<Output = T>> Neg for Vec3<T> {
     /// use fiz_math::Vec3;
     ///
     /// assert_eq!(-Vec3(1, 2, 3), Vec3(-1, -2, -3));
+    /// assert_eq!(-Vec3(1, -2, 3), Vec3(-1, 2, -3));
+    /// assert_eq!(-Vec3(1.0, -2.0, 3.0), Vec3(-1.0, 2.0, -3.0));
     /// ```
     fn neg(self) -> Self {
         Vec3(-self.0, -self.1, -self.2)
@@ -243,6 +1233,23 @@ impl<T: Sub<Output = T> + Copy> Vec3<T> {
     }
 }
 
+impl<T: Sub<Output = T> + Copy> SubAssign for Vec3<T> {
+    /// sub_assign performs in-place component-wise subtraction of two vectors.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::Vec3;
+    ///
+    /// let mut a = Vec3(1, 2, 3);
+    /// a -= Vec3(4, 5, 6);
+    /// assert_eq!(a, Vec3(-3, -3, -3));
+    /// ```
+    fn sub_assign(&mut self, _rhs: Self) {
+        *self = *self - _rhs;
+    }
+}
+
 impl<T: Mul<Output = T>> Mul for Vec3<T> {
     type Output = Self;
 
@@ -276,6 +1283,74 @@ impl<T: Mul<Output = T> + Copy> Vec3<T> {
     pub fn mul_scalar(self, _rhs: T) -> Self {
         Vec3(self.0 * _rhs, self.1 * _rhs, self.2 * _rhs)
     }
+
+    /// product returns the product of all of the components of the vector,
+    /// e.g. the volume of a box whose size is the vector.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::Vec3;
+    ///
+    /// assert_eq!(Vec3(2, 3, 4).product(), 24);
+    /// ```
+    pub fn product(self) -> T {
+        self.0 * self.1 * self.2
+    }
+}
+
+impl<T: Mul<Output = T> + Copy> Mul<T> for Vec3<T> {
+    type Output = Self;
+
+    /// mul performs scalar multiplication on a vector, it is identical to
+    /// mul_scalar.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::Vec3;
+    ///
+    /// assert_eq!(Vec3(1, 2, 3) * 2, Vec3(2, 4, 6));
+    /// assert_eq!(Vec3(1.5, 2.0, 3.0) * 2.0, Vec3(3.0, 4.0, 6.0));
+    /// ```
+    fn mul(self, _rhs: T) -> Self {
+        Vec3(self.0 * _rhs, self.1 * _rhs, self.2 * _rhs)
+    }
+}
+
+impl<T: Mul<Output = T> + Copy> MulAssign for Vec3<T> {
+    /// mul_assign performs in-place component-wise multiplication of two
+    /// vectors.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::Vec3;
+    ///
+    /// let mut a = Vec3(1, 2, 3);
+    /// a *= Vec3(4, 5, 6);
+    /// assert_eq!(a, Vec3(4, 10, 18));
+    /// ```
+    fn mul_assign(&mut self, _rhs: Self) {
+        *self = *self * _rhs;
+    }
+}
+
+impl<T: Mul<Output = T> + Copy> MulAssign<T> for Vec3<T> {
+    /// mul_assign performs in-place scalar multiplication on a vector.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::Vec3;
+    ///
+    /// let mut a = Vec3(1.0, 2.0, 3.0);
+    /// a *= 2.0;
+    /// assert_eq!(a, Vec3(2.0, 4.0, 6.0));
+    /// ```
+    fn mul_assign(&mut self, _rhs: T) {
+        *self = *self * _rhs;
+    }
 }
 
 impl<T: Div<Output = T>> Div for Vec3<T> {
@@ -313,6 +1388,91 @@ impl<T: Div<Output = T> + Copy> Vec3<T> {
     }
 }
 
+impl<T: Div<Output = T> + Copy> Div<T> for Vec3<T> {
+    type Output = Self;
+
+    /// div performs scalar division on a vector, it is identical to
+    /// div_scalar. Integer components are truncated as usual.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::Vec3;
+    ///
+    /// assert_eq!(Vec3(3, 5, 6) / 2, Vec3(1, 2, 3));
+    /// assert_eq!(Vec3(2.0, 4.0, 6.0) / 2.0, Vec3(1.0, 2.0, 3.0));
+    /// ```
+    fn div(self, _rhs: T) -> Self {
+        Vec3(self.0 / _rhs, self.1 / _rhs, self.2 / _rhs)
+    }
+}
+
+impl<T: Div<Output = T> + Copy> DivAssign for Vec3<T> {
+    /// div_assign performs in-place component-wise division of two vectors.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::Vec3;
+    ///
+    /// let mut a = Vec3(4, 5, 9);
+    /// a /= Vec3(1, 2, 3);
+    /// assert_eq!(a, Vec3(4, 2, 3));
+    /// ```
+    fn div_assign(&mut self, _rhs: Self) {
+        *self = *self / _rhs;
+    }
+}
+
+impl<T: Div<Output = T> + Copy> DivAssign<T> for Vec3<T> {
+    /// div_assign performs in-place scalar division on a vector.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::Vec3;
+    ///
+    /// let mut a = Vec3(2.0, 4.0, 6.0);
+    /// a /= 2.0;
+    /// assert_eq!(a, Vec3(1.0, 2.0, 3.0));
+    /// ```
+    fn div_assign(&mut self, _rhs: T) {
+        *self = *self / _rhs;
+    }
+}
+
+impl Vec3<bool> {
+    /// all tells if all of the components of this mask are true.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::Vec3;
+    ///
+    /// assert!(Vec3(true, true, true).all());
+    /// assert!(!Vec3(true, false, true).all());
+    /// assert!(Vec3(1, 2, 3).cmplt(Vec3(4, 4, 4)).all());
+    /// ```
+    pub fn all(self) -> bool {
+        self.0 && self.1 && self.2
+    }
+
+    /// any tells if any of the components of this mask are true.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::Vec3;
+    ///
+    /// assert!(Vec3(false, true, false).any());
+    /// assert!(!Vec3(false, false, false).any());
+    /// assert!(Vec3(1, 5, 3).cmpgt(Vec3(4, 4, 4)).any());
+    /// ```
+    pub fn any(self) -> bool {
+        self.0 || self.1 || self.2
+    }
+}
+
 impl<T: Clamp<Elem = T> + Copy> Clamp for Vec3<T> {
     type Elem = T;
 
@@ -340,6 +1500,45 @@ impl<T> AsRef<Vec3<T>> for Vec3<T> {
     }
 }
 
+impl<T> AsRef<[T]> for Vec3<T> {
+    /// as_ref returns a slice over the components of the vector, in order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::Vec3;
+    ///
+    /// let v = Vec3(1, 2, 3);
+    /// let s: &[i32] = v.as_ref();
+    /// assert_eq!(s.len(), 3);
+    /// assert_eq!(s.iter().sum::<i32>(), 6);
+    /// ```
+    fn as_ref(&self) -> &[T] {
+        // Vec3 is repr(C), so its components are laid out like a [T; 3].
+        unsafe { slice::from_raw_parts(self as *const Self as *const T, 3) }
+    }
+}
+
+impl<T> AsMut<[T]> for Vec3<T> {
+    /// as_mut returns a mutable slice over the components of the vector, in
+    /// order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::Vec3;
+    ///
+    /// let mut v = Vec3(1, 2, 3);
+    /// for c in v.as_mut().iter_mut() {
+    ///     *c *= 2;
+    /// }
+    /// assert_eq!(v, Vec3(2, 4, 6));
+    /// ```
+    fn as_mut(&mut self) -> &mut [T] {
+        unsafe { slice::from_raw_parts_mut(self as *mut Self as *mut T, 3) }
+    }
+}
+
 impl<T: PartialOrd> Vec3<T> {
     /// any_less tells if any component of the other vector is less than any
     /// component of this vector.
@@ -372,30 +1571,213 @@ impl<T: PartialOrd> Vec3<T> {
         let o = other.as_ref();
         self.0 > o.0 || self.1 > o.1 || self.2 > o.2
     }
+
+    /// cmpeq returns a mask of which components of this vector are equal to
+    /// the ones in the other vector.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::Vec3;
+    ///
+    /// assert_eq!(Vec3(1, 4, 3).cmpeq(Vec3(4, 4, 4)), Vec3(false, true, false));
+    /// ```
+    pub fn cmpeq(self, other: Self) -> Vec3<bool> {
+        self.zip(other, |a, b| a == b)
+    }
+
+    /// cmpne returns a mask of which components of this vector are not equal
+    /// to the ones in the other vector.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::Vec3;
+    ///
+    /// assert_eq!(Vec3(1, 4, 3).cmpne(Vec3(4, 4, 4)), Vec3(true, false, true));
+    /// ```
+    pub fn cmpne(self, other: Self) -> Vec3<bool> {
+        self.zip(other, |a, b| a != b)
+    }
+
+    /// cmplt returns a mask of which components of this vector are less than
+    /// the ones in the other vector.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::Vec3;
+    ///
+    /// assert_eq!(Vec3(1, 5, 3).cmplt(Vec3(4, 4, 4)), Vec3(true, false, true));
+    /// ```
+    pub fn cmplt(self, other: Self) -> Vec3<bool> {
+        self.zip(other, |a, b| a < b)
+    }
+
+    /// cmple returns a mask of which components of this vector are less than
+    /// or equal to the ones in the other vector.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::Vec3;
+    ///
+    /// assert_eq!(Vec3(1, 5, 4).cmple(Vec3(4, 4, 4)), Vec3(true, false, true));
+    /// ```
+    pub fn cmple(self, other: Self) -> Vec3<bool> {
+        self.zip(other, |a, b| a <= b)
+    }
+
+    /// cmpgt returns a mask of which components of this vector are greater
+    /// than the ones in the other vector.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::Vec3;
+    ///
+    /// assert_eq!(Vec3(1, 5, 3).cmpgt(Vec3(4, 4, 4)), Vec3(false, true, false));
+    /// ```
+    pub fn cmpgt(self, other: Self) -> Vec3<bool> {
+        self.zip(other, |a, b| a > b)
+    }
+
+    /// cmpge returns a mask of which components of this vector are greater
+    /// than or equal to the ones in the other vector.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::Vec3;
+    ///
+    /// assert_eq!(Vec3(1, 5, 4).cmpge(Vec3(4, 4, 4)), Vec3(false, true, true));
+    /// ```
+    pub fn cmpge(self, other: Self) -> Vec3<bool> {
+        self.zip(other, |a, b| a >= b)
+    }
+}
+
+impl<T: PartialEq> PartialEq for Vec3<T> {
+    /// eq tests for component-wise binary equality of two vectors.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::Vec3;
+    ///
+    /// let a = Vec3(4.0, 5.0, 9.0);
+    /// let b = Vec3(4.0, 5.0, 9.00000000000000000000001);
+    /// assert_eq!(a, b);
+    /// ```
+    ///
+    /// ```
+    /// use fiz_math::Vec3;
+    ///
+    /// let a = Vec3(4, 5, 9);
+    /// let b = Vec3(4, 5, 9);
+    /// assert_eq!(a, b);
+    /// ```
+    fn eq(&self, _rhs: &Self) -> bool {
+        self.0 == _rhs.0 && self.1 == _rhs.1 && self.2 == _rhs.2
+    }
+}
+
+impl<T: Eq> Eq for Vec3<T> {}
+
+impl<T: Ord> Vec3<T> {
+    /// cmp_lex compares the two vectors lexicographically, i.e. by their x
+    /// components first, then by their y components, and then by their z
+    /// components. This is a total order suitable for sorting integer vectors
+    /// (e.g. with sort_by), but unlike partial_cmp it is not a component-wise
+    /// order: a vector may compare as less than another whose components are
+    /// not all greater.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::cmp::Ordering;
+    /// use fiz_math::Vec3;
+    ///
+    /// let mut v = vec![Vec3(2, 0, 0), Vec3(1, 5, 5), Vec3(1, 0, 9), Vec3(1, 0, 0)];
+    /// v.sort_by(|a, b| a.cmp_lex(b));
+    /// assert_eq!(v, vec![Vec3(1, 0, 0), Vec3(1, 0, 9), Vec3(1, 5, 5), Vec3(2, 0, 0)]);
+    /// assert_eq!(Vec3(1, 9, 9).cmp_lex(&Vec3(2, 0, 0)), Ordering::Less);
+    /// ```
+    pub fn cmp_lex(&self, other: &Self) -> Ordering {
+        self.0
+            .cmp(&other.0)
+            .then_with(|| self.1.cmp(&other.1))
+            .then_with(|| self.2.cmp(&other.2))
+    }
+}
+
+/// Lex wraps a Vec3 such that it is ordered lexicographically (see cmp_lex),
+/// allowing integer vectors to be used as keys in a BTreeMap or BTreeSet. Vec3
+/// itself does not implement Ord, as its PartialOrd is component-wise.
+///
+/// # Examples
+///
+/// ```
+/// use std::collections::BTreeSet;
+/// use fiz_math::{Lex, Vec3};
+///
+/// let mut set = BTreeSet::new();
+/// set.insert(Lex(Vec3(2, 0, 0)));
+/// set.insert(Lex(Vec3(1, 5, 5)));
+/// set.insert(Lex(Vec3(1, 0, 9)));
+/// set.insert(Lex(Vec3(1, 5, 5)));
+/// assert_eq!(set.len(), 3);
+/// assert!(set.contains(&Lex(Vec3(1, 0, 9))));
+///
+/// let sorted: Vec<_> = set.into_iter().map(|l| l.0).collect();
+/// assert_eq!(sorted, vec![Vec3(1, 0, 9), Vec3(1, 5, 5), Vec3(2, 0, 0)]);
+/// ```
+///
+/// Note that this is not a component-wise order:
+///
+/// ```
+/// use fiz_math::{Lex, Vec3};
+///
+/// assert!(Lex(Vec3(1, 9, 9)) < Lex(Vec3(2, 0, 0)));
+/// assert_eq!(Vec3(1, 9, 9).partial_cmp(&Vec3(2, 0, 0)), None);
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Lex<T>(pub Vec3<T>);
+
+impl<T: Ord> PartialOrd for Lex<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T: Ord> Ord for Lex<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.cmp_lex(&other.0)
+    }
 }
 
-impl<T: PartialEq> PartialEq for Vec3<T> {
-    /// eq tests for component-wise binary equality of two vectors.
+impl<T: Hash> Hash for Vec3<T> {
+    /// hash feeds each component of the vector, in order, into the given
+    /// hasher. Floating-point vectors do not implement Hash, as their
+    /// components do not.
     ///
     /// # Examples
     ///
     /// ```
+    /// use std::collections::HashSet;
     /// use fiz_math::Vec3;
     ///
-    /// let a = Vec3(4.0, 5.0, 9.0);
-    /// let b = Vec3(4.0, 5.0, 9.00000000000000000000001);
-    /// assert_eq!(a, b);
-    /// ```
-    ///
-    /// ```
-    /// use fiz_math::Vec3;
-    ///
-    /// let a = Vec3(4, 5, 9);
-    /// let b = Vec3(4, 5, 9);
-    /// assert_eq!(a, b);
-    /// ```
-    fn eq(&self, _rhs: &Self) -> bool {
-        self.0 == _rhs.0 && self.1 == _rhs.1 && self.2 == _rhs.2
+    /// let mut voxels = HashSet::new();
+    /// voxels.insert(Vec3(1, 2, 3));
+    /// voxels.insert(Vec3(4, 5, 6));
+    /// voxels.insert(Vec3(1, 2, 3));
+    /// assert_eq!(voxels.len(), 2);
+    /// assert!(voxels.contains(&Vec3(4, 5, 6)));
+    /// ```
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.hash(state);
+        self.1.hash(state);
+        self.2.hash(state);
     }
 }
 
@@ -433,6 +1815,7 @@ impl<T: PartialOrd> Vec3<T> {
     /// use fiz_math::Vec3;
     ///
     /// assert_eq!(Vec3(0, 1, 2).min(Vec3(-1, 0, 3)), Vec3(-1, 0, 2));
+    /// assert_eq!(Vec3(1, 5, 3).min(Vec3(4, 2, 6)), Vec3(1, 2, 3));
     /// ```
     pub fn min(self, other: Self) -> Self {
         Vec3(if self.0 < other.0 {
@@ -461,6 +1844,7 @@ impl<T: PartialOrd> Vec3<T> {
     /// use fiz_math::Vec3;
     ///
     /// assert_eq!(Vec3(0, 1, 2).max(Vec3(-1, 0, 3)), Vec3(0, 1, 3));
+    /// assert_eq!(Vec3(1, 5, 3).max(Vec3(4, 2, 6)), Vec3(4, 5, 6));
     /// ```
     pub fn max(self, other: Self) -> Self {
         Vec3(if self.0 > other.0 {
@@ -479,6 +1863,66 @@ impl<T: PartialOrd> Vec3<T> {
                  other.2
              })
     }
+
+    /// clamp_vec returns the vector with each component clamped to the range
+    /// of the corresponding components in the `min` and `max` vectors. If a
+    /// component of `min` is greater than the one in `max`, then the `max`
+    /// component is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::Vec3;
+    ///
+    /// let a = Vec3(-1, 5, 3);
+    /// assert_eq!(a.clamp_vec(Vec3(0, 0, 0), Vec3(4, 4, 4)), Vec3(0, 4, 3));
+    /// assert_eq!(a.clamp_vec(Vec3(5, 5, 5), Vec3(3, 3, 3)), Vec3(3, 3, 3));
+    /// ```
+    pub fn clamp_vec(self, min: Self, max: Self) -> Self {
+        self.max(min).min(max)
+    }
+
+    /// min_component returns the smallest component of the vector.
+    ///
+    /// Components are compared in x, y, z order and NaN never compares less
+    /// than anything, so a NaN y or z component is skipped while a NaN x
+    /// component is returned as-is.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::Vec3;
+    ///
+    /// assert_eq!(Vec3(1, 5, 3).min_component(), 1);
+    /// assert_eq!(Vec3(2.0, -1.0, 0.5).min_component(), -1.0);
+    /// assert_eq!(Vec3(2.0, std::f64::NAN, 0.5).min_component(), 0.5);
+    /// ```
+    pub fn min_component(self) -> T {
+        let Vec3(x, y, z) = self;
+        let m = if y < x { y } else { x };
+        if z < m { z } else { m }
+    }
+
+    /// max_component returns the largest component of the vector.
+    ///
+    /// Components are compared in x, y, z order and NaN never compares greater
+    /// than anything, so a NaN y or z component is skipped while a NaN x
+    /// component is returned as-is.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::Vec3;
+    ///
+    /// assert_eq!(Vec3(1, 5, 3).max_component(), 5);
+    /// assert_eq!(Vec3(2.0, -1.0, 0.5).max_component(), 2.0);
+    /// assert!(Vec3(std::f64::NAN, 1.0, 0.5).max_component().is_nan());
+    /// ```
+    pub fn max_component(self) -> T {
+        let Vec3(x, y, z) = self;
+        let m = if y > x { y } else { x };
+        if z > m { z } else { m }
+    }
 }
 
 impl<T: Zero> Zero for Vec3<T> {
@@ -515,6 +1959,24 @@ impl<T: Zero> Zero for Vec3<T> {
     }
 }
 
+impl<T: Zero> Sum for Vec3<T> {
+    /// sum returns the component-wise sum of all the vectors in the iterator,
+    /// or the zero vector if it is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::Vec3;
+    ///
+    /// let v = vec![Vec3(1.0, 2.0, 3.0), Vec3(4.0, 5.0, 6.0), Vec3(7.0, 8.0, 9.0)];
+    /// assert_eq!(v.iter().copied().sum::<Vec3<f32>>(), Vec3(12.0, 15.0, 18.0));
+    /// assert_eq!(Vec::new().into_iter().sum::<Vec3<i32>>(), Vec3(0, 0, 0));
+    /// ```
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::zero(), |a, b| a + b)
+    }
+}
+
 impl<T: Num + Copy> Vec3<T> {
     /// dot returns the dot product of self and b. For length calculations use
     /// length or length_sq functions instead (for clarity).
@@ -526,11 +1988,72 @@ impl<T: Num + Copy> Vec3<T> {
     ///
     /// let x = Vec3(1, 2, 3);
     /// assert_eq!(x.dot(x), 14);
+    /// assert_eq!(x.dot(Vec3(4, 5, 6)), 32);
+    /// ```
+    ///
+    /// ```
+    /// use fiz_math::Vec3;
+    ///
+    /// let x = Vec3(1.0, 2.0, 3.0);
+    /// assert_eq!(x.dot(Vec3(4.0, 5.0, 6.0)), 32.0);
     /// ```
     pub fn dot(self, b: Self) -> T {
         self.0 * b.0 + self.1 * b.1 + self.2 * b.2
     }
 
+    /// dot_into returns the dot product of self and b, with each component
+    /// first widened into the larger type `U`. This avoids overflow of the
+    /// intermediate products for small integer types, where `dot` would
+    /// overflow.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::Vec3;
+    ///
+    /// // 200 * 200 * 3 does not fit in a u8 (or even a u16).
+    /// let x = Vec3::<u8>(200, 200, 200);
+    /// assert_eq!(x.dot_into::<u32>(x), 120000);
+    /// assert_eq!(Vec3(255u8, 0, 1).dot_into::<u16>(Vec3(255, 7, 2)), 65027);
+    /// ```
+    ///
+    /// For floats it can be used to accumulate in higher precision, e.g. a
+    /// `Vec3<f32>` into an `f64`:
+    ///
+    /// ```
+    /// use fiz_math::Vec3;
+    ///
+    /// let a = Vec3(1.0e8f32, 1.0, -1.0e8);
+    /// let b = Vec3(1.0f32, 1.0, 1.0);
+    /// assert_eq!(a.dot(b), 0.0);
+    /// assert_eq!(a.dot_into::<f64>(b), 1.0);
+    /// ```
+    pub fn dot_into<U: Num + From<T>>(self, b: Self) -> U {
+        U::from(self.0) * U::from(b.0) + U::from(self.1) * U::from(b.1) +
+        U::from(self.2) * U::from(b.2)
+    }
+
+    /// scalar_triple returns the scalar triple product of the three vectors,
+    /// i.e. `a.dot(b.cross(c))`. It is the signed volume of the parallelepiped
+    /// spanned by the vectors, and is positive when they form a right-handed
+    /// system.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::Vec3;
+    ///
+    /// let (x, y, z) = (Vec3(1, 0, 0), Vec3(0, 1, 0), Vec3(0, 0, 1));
+    /// assert_eq!(Vec3::scalar_triple(x, y, z), 1);
+    /// assert_eq!(Vec3::scalar_triple(y, x, z), -1);
+    ///
+    /// let (a, b, c) = (Vec3(2.0, 0.0, 0.0), Vec3(0.0, 3.0, 0.0), Vec3(1.0, 1.0, 4.0));
+    /// assert_eq!(Vec3::scalar_triple(a, b, c), 24.0);
+    /// ```
+    pub fn scalar_triple(a: Self, b: Self, c: Self) -> T {
+        a.dot(b.cross(c))
+    }
+
     /// length_sq returns the magnitude squared of this vector, useful primarily
     /// for comparing distances.
     ///
@@ -540,10 +2063,73 @@ impl<T: Num + Copy> Vec3<T> {
     /// use fiz_math::Vec3;
     ///
     /// assert_eq!(Vec3(1, 2, 3).length_sq(), 14);
+    /// assert_eq!(Vec3(3.0f32, 4.0, 0.0).length_sq(), 25.0);
     /// ```
     pub fn length_sq(self) -> T {
         self.dot(self)
     }
+
+    /// midpoint returns the point halfway between the `self` and `other`
+    /// vectors. Integer components are truncated as usual.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::Vec3;
+    ///
+    /// assert_eq!(Vec3(0.0, 1.0, 2.0).midpoint(Vec3(1.0, 2.0, 4.0)), Vec3(0.5, 1.5, 3.0));
+    /// assert_eq!(Vec3(0, 1, 2).midpoint(Vec3(1, 2, 4)), Vec3(0, 1, 3));
+    /// ```
+    pub fn midpoint(self, other: Self) -> Self {
+        (self + other) / (T::one() + T::one())
+    }
+}
+
+impl<T: Num + PartialOrd + Copy> Vec3<T> {
+    /// distance_sq returns the distance squared between the `self` and `other`
+    /// vectors, useful primarily for comparing distances. The difference of each
+    /// component is taken such that unsigned vectors do not underflow.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::Vec3;
+    ///
+    /// assert_eq!(Vec3(1, 2, 3).distance_sq(Vec3(4, 6, 3)), 25);
+    /// assert_eq!(Vec3(0.0, 0.0, 0.0).distance_sq(Vec3(3.0, 4.0, 0.0)), 25.0);
+    /// assert_eq!(Vec3(2u32, 0, 0).distance_sq(Vec3(1, 0, 0)), 1);
+    /// ```
+    pub fn distance_sq(self, other: Self) -> T {
+        let d = |a: T, b: T| if a > b { a - b } else { b - a };
+        Vec3(d(self.0, other.0), d(self.1, other.1), d(self.2, other.2)).length_sq()
+    }
+}
+
+impl<T: Mul<Output = T> + Sub<Output = T> + Copy> Vec3<T> {
+    /// cross returns the cross product of self and b, that is a vector which is
+    /// perpendicular to both.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::Vec3;
+    ///
+    /// assert_eq!(Vec3(1, 0, 0).cross(Vec3(0, 1, 0)), Vec3(0, 0, 1));
+    /// assert_eq!(Vec3(0, 1, 0).cross(Vec3(1, 0, 0)), Vec3(0, 0, -1));
+    /// ```
+    ///
+    /// The cross product of two parallel vectors is zero:
+    ///
+    /// ```
+    /// use fiz_math::{Vec3, Zero};
+    ///
+    /// assert!(Vec3(1.0, 2.0, 3.0).cross(Vec3(2.0, 4.0, 6.0)).is_zero());
+    /// ```
+    pub fn cross(self, b: Self) -> Self {
+        Vec3(self.1 * b.2 - self.2 * b.1,
+             self.2 * b.0 - self.0 * b.2,
+             self.0 * b.1 - self.1 * b.0)
+    }
 }
 
 impl<T: Float> Vec3<T> {
@@ -558,11 +2144,27 @@ impl<T: Float> Vec3<T> {
     ///
     /// let l = Vec3(1.0, 2.0, 3.0).length();
     /// assert!(l.equal(3.74165738));
+    /// assert_eq!(Vec3(3.0f32, 4.0, 0.0).length(), 5.0);
     /// ```
     pub fn length(self) -> T {
         self.length_sq().sqrt()
     }
 
+    /// distance returns the distance between the `self` and `other` vectors.
+    /// Use distance_sq instead when comparing distances, because it avoids the
+    /// extra sqrt operation needed by this method.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::Vec3;
+    ///
+    /// assert_eq!(Vec3(0.0, 0.0, 0.0).distance(Vec3(3.0, 4.0, 0.0)), 5.0);
+    /// ```
+    pub fn distance(self, other: Self) -> T {
+        (other - self).length()
+    }
+
     /// normalize returns the normalized (i.e. length/magnitude == 1) vector
     /// representing self. If the vector's length is zero and division by zero
     /// would occur, then None is returned.
@@ -574,6 +2176,15 @@ impl<T: Float> Vec3<T> {
     ///
     /// let n = Vec3(1.0, 10.0, 100.0).normalize().unwrap();
     /// assert!(n.almost_equal(Vec3(0.01, 0.1, 1.0), 1e-2));
+    /// assert_eq!(Vec3(0.0, 3.0, 0.0).normalize(), Some(Vec3(0.0, 1.0, 0.0)));
+    /// ```
+    ///
+    /// The zero vector has no direction, so it cannot be normalized:
+    ///
+    /// ```
+    /// use fiz_math::Vec3;
+    ///
+    /// assert_eq!(Vec3(0.0, 0.0, 0.0).normalize(), None);
     /// ```
     pub fn normalize(self) -> Option<Self> {
         let length = self.length();
@@ -584,6 +2195,28 @@ impl<T: Float> Vec3<T> {
         }
     }
 
+    /// normalize_or_zero returns the normalized vector representing self, or
+    /// the zero vector if the vector's length is (almost) zero, as determined
+    /// by the equal function.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::{Vec3, Zero};
+    ///
+    /// assert_eq!(Vec3(0.0, 3.0, 0.0).normalize_or_zero(), Vec3(0.0, 1.0, 0.0));
+    /// assert_eq!(Vec3(0.0, 0.0, 0.0).normalize_or_zero(), Vec3::zero());
+    /// assert_eq!(Vec3(1e-10, 0.0, 0.0).normalize_or_zero(), Vec3::zero());
+    /// ```
+    pub fn normalize_or_zero(self) -> Self {
+        let length = self.length();
+        if length.equal(T::zero()) {
+            Self::zero()
+        } else {
+            self.div_scalar(length)
+        }
+    }
+
     /// project returns a vector representing the projection of the `self` vector
     /// onto the `other` vector.
     ///
@@ -600,6 +2233,137 @@ impl<T: Float> Vec3<T> {
         other.mul_scalar(self.dot(other) / other.length_sq())
     }
 
+    /// reject returns a vector representing the rejection of the `self` vector
+    /// from the `other` vector, that is the component of `self` perpendicular
+    /// to `other`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::Vec3;
+    ///
+    /// let a = Vec3(1.0f64, 2.0, 4.0);
+    /// let b = Vec3(1.0, 2.0, 3.0);
+    /// assert!(a.reject(b).dot(b).abs() < 1e-10);
+    /// assert!((a.project(b) + a.reject(b)).almost_equal(a, 1e-10));
+    /// ```
+    pub fn reject(self, other: Self) -> Self {
+        self - self.project(other)
+    }
+
+    /// reflect returns the vector `self` reflected about the surface with the
+    /// given normal. The normal is assumed to be normalized (i.e. of unit
+    /// length).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::Vec3;
+    ///
+    /// let floor = Vec3(0.0, 1.0, 0.0);
+    /// assert_eq!(Vec3(1.0, -1.0, 0.0).reflect(floor), Vec3(1.0, 1.0, 0.0));
+    /// ```
+    pub fn reflect(self, normal: Self) -> Self {
+        let two = T::one() + T::one();
+        self - normal * (two * self.dot(normal))
+    }
+
+    /// refract returns the vector `self` refracted through the surface with the
+    /// given normal, according to Snell's law. `eta` is the ratio of the
+    /// refractive indices, i.e. that of the medium being left over that of
+    /// the medium being entered.
+    ///
+    /// Like GLSL's refract, `self` is the incident direction pointing towards
+    /// the surface, and both it and the normal (which faces against it) are
+    /// assumed to be normalized. In the case of total internal reflection, the
+    /// zero vector is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::Vec3;
+    ///
+    /// let n = Vec3(0.0, 1.0, 0.0);
+    /// assert_eq!(Vec3(0.0, -1.0, 0.0).refract(n, 1.0 / 1.5), Vec3(0.0, -1.0, 0.0));
+    ///
+    /// // Entering glass from air bends the ray towards the normal.
+    /// let i = Vec3(1.0f64, -1.0, 0.0).normalize().unwrap();
+    /// let r = i.refract(n, 1.0 / 1.5);
+    /// assert!(r.0 > 0.0 && r.0 < i.0);
+    /// assert!((r.length() - 1.0).abs() < 1e-10);
+    /// ```
+    ///
+    /// Leaving glass at a shallow angle causes total internal reflection:
+    ///
+    /// ```
+    /// use fiz_math::{Vec3, Zero};
+    ///
+    /// let n = Vec3(0.0, 1.0, 0.0);
+    /// let i = Vec3(1.0, -1.0, 0.0).normalize().unwrap();
+    /// assert_eq!(i.refract(n, 1.5), Vec3::zero());
+    /// ```
+    pub fn refract(self, normal: Self, eta: T) -> Self {
+        let cos = normal.dot(self);
+        let k = T::one() - eta * eta * (T::one() - cos * cos);
+        if k < T::zero() {
+            Self::zero()
+        } else {
+            self * eta - normal * (eta * cos + k.sqrt())
+        }
+    }
+
+    /// faceforward returns the normal `self` flipped, if needed, such that it
+    /// faces against the `incident` vector. Like GLSL's faceforward, `self` is
+    /// returned if `nref.dot(incident) < 0`, and `-self` otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::Vec3;
+    ///
+    /// let n = Vec3(0.0, 1.0, 0.0);
+    /// assert_eq!(n.faceforward(Vec3(1.0, -1.0, 0.0), n), n);
+    /// assert_eq!(n.faceforward(Vec3(1.0, 1.0, 0.0), n), -n);
+    /// ```
+    pub fn faceforward(self, incident: Self, nref: Self) -> Self {
+        if nref.dot(incident) < T::zero() {
+            self
+        } else {
+            -self
+        }
+    }
+
+    /// angle_between returns the angle in radians between the `self` and
+    /// `other` vectors. The cosine of the angle is clamped to the range of
+    /// [-1, 1] before computing the arc cosine, such that rounding errors for
+    /// (almost) parallel vectors do not produce NaN.
+    ///
+    /// The angle is undefined if either vector is of zero length (or contains
+    /// NaN), in which case NaN is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::f64::consts::FRAC_PI_2;
+    /// use fiz_math::{Vec3, Float};
+    ///
+    /// let a = Vec3(1.0, 0.0, 0.0);
+    /// assert!(a.angle_between(Vec3(0.0, 1.0, 0.0)).equal(FRAC_PI_2));
+    /// assert_eq!(Vec3(0.7, 0.7, 0.7).angle_between(Vec3(1.4, 1.4, 1.4)), 0.0);
+    /// assert!(a.angle_between(Vec3(0.0, 0.0, 0.0)).is_nan());
+    /// ```
+    pub fn angle_between(self, other: Self) -> T {
+        let lengths = self.length() * other.length();
+        if lengths == T::zero() {
+            return T::nan();
+        }
+        let cos = self.dot(other) / lengths;
+        if cos.is_nan() {
+            return cos;
+        }
+        cos.max(-T::one()).min(T::one()).acos()
+    }
+
     /// lerp returns a vector representing the linear interpolation between the
     /// `self` and `other` vectors. The parameter `t` is the amount to interpolate
     /// between the vectors (e.g. `0.0 - 1.0`).
@@ -611,10 +2375,103 @@ impl<T: Float> Vec3<T> {
     ///
     /// let a = Vec3(1.25, 1.25, 1.25);
     /// let b = Vec3(2.0, 2.0, 2.0);
-    /// assert_eq!(a.lerp(b, 0.25), Vec3(0.625, 0.625, 0.625));
+    /// assert_eq!(a.lerp(b, 0.0), a);
+    /// assert_eq!(a.lerp(b, 0.25), Vec3(1.4375, 1.4375, 1.4375));
+    /// assert_eq!(a.lerp(b, 0.5), Vec3(1.625, 1.625, 1.625));
+    /// assert_eq!(a.lerp(b, 1.0), b);
     /// ```
     pub fn lerp(self, other: Self, t: T) -> Self {
-        self * other.mul_scalar(t)
+        Vec3(self.0.lerp(other.0, t), self.1.lerp(other.1, t), self.2.lerp(other.2, t))
+    }
+
+    /// clamp_length_max returns the vector scaled down to have a length of at
+    /// most `max`. Vectors which are already short enough are returned as-is.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::Vec3;
+    ///
+    /// assert_eq!(Vec3(0.0, 6.0, 8.0).clamp_length_max(5.0), Vec3(0.0, 3.0, 4.0));
+    /// assert_eq!(Vec3(0.0, 3.0, 4.0).clamp_length_max(10.0), Vec3(0.0, 3.0, 4.0));
+    /// ```
+    pub fn clamp_length_max(self, max: T) -> Self {
+        let length = self.length();
+        if length > max && length > T::zero() {
+            self.mul_scalar(max / length)
+        } else {
+            self
+        }
+    }
+
+    /// clamp_length_min returns the vector scaled up to have a length of at
+    /// least `min`. Vectors which are already long enough are returned as-is,
+    /// as is the zero vector, which has no direction to scale along.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::{Vec3, Zero};
+    ///
+    /// assert_eq!(Vec3(0.0, 0.6, 0.8).clamp_length_min(5.0), Vec3(0.0, 3.0, 4.0));
+    /// assert_eq!(Vec3(0.0, 6.0, 8.0).clamp_length_min(5.0), Vec3(0.0, 6.0, 8.0));
+    /// assert_eq!(Vec3::<f32>::zero().clamp_length_min(5.0), Vec3::zero());
+    /// ```
+    pub fn clamp_length_min(self, min: T) -> Self {
+        let length = self.length();
+        if length < min && length > T::zero() {
+            self.mul_scalar(min / length)
+        } else {
+            self
+        }
+    }
+
+    /// centroid returns the mean of the given points, or None if there are no
+    /// points.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::Vec3;
+    ///
+    /// let triangle = [Vec3(0.0, 0.0, 0.0), Vec3(3.0, 0.0, 0.0), Vec3(0.0, 3.0, 0.0)];
+    /// assert_eq!(Vec3::centroid(&triangle), Some(Vec3(1.0, 1.0, 0.0)));
+    /// assert_eq!(Vec3::<f32>::centroid(&[]), None);
+    /// ```
+    pub fn centroid(points: &[Self]) -> Option<Self> {
+        if points.is_empty() {
+            return None;
+        }
+        let sum: Self = points.iter().cloned().sum();
+        Some(sum.div_scalar(T::from(points.len()).unwrap()))
+    }
+
+    /// move_towards returns the point moved from `self` towards `target` by a
+    /// distance of at most `max_delta`. If the target is within reach, it is
+    /// returned exactly, i.e. the target is never overshot.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::Vec3;
+    ///
+    /// let target = Vec3(0.0, 0.0, 10.0);
+    /// let mut p = Vec3(0.0, 0.0, 0.0);
+    /// p = p.move_towards(target, 4.0);
+    /// assert_eq!(p, Vec3(0.0, 0.0, 4.0));
+    /// p = p.move_towards(target, 4.0);
+    /// assert_eq!(p, Vec3(0.0, 0.0, 8.0));
+    /// p = p.move_towards(target, 4.0);
+    /// assert_eq!(p, target);
+    /// ```
+    pub fn move_towards(self, target: Self, max_delta: T) -> Self {
+        let delta = target - self;
+        let distance = delta.length();
+        if distance <= max_delta || distance == T::zero() {
+            target
+        } else {
+            self + delta.mul_scalar(max_delta / distance)
+        }
     }
 }
 
@@ -654,8 +2511,36 @@ impl<F, T> Vec3<T>
     }
 }
 
+macro_rules! impl_as_to {
+    ($ty:ty; $($name:ident, $to:ident);*) => (
+        impl Vec3<$ty> {$(
+            #[doc = concat!(stringify!($name), " converts each component to ", stringify!($to),
+                            " with an `as` cast.")]
+            pub fn $name(self) -> Vec3<$to> {
+                self.map(|c| c as $to)
+            }
+        )*}
+    );
+}
+
+macro_rules! impl_as {
+    ($($ty:ty),*) => ($(
+        impl_as_to! { $ty;
+            as_f32, f32; as_f64, f64;
+            as_i8, i8; as_u8, u8;
+            as_i16, i16; as_u16, u16;
+            as_i32, i32; as_u32, u32;
+            as_i64, i64; as_u64, u64;
+            as_isize, isize; as_usize, usize
+        }
+    )*);
+}
+
+impl_as! { f32, f64, i8, u8, i16, u16, i32, u32, i64, u64, isize, usize }
+
 swizzle!(x, Vec3);
 swizzle!(y, Vec3);
 swizzle!(z, Vec3);
 swizzle!(2, Vec3);
+swizzle!(2, z, Vec3);
 swizzle!(3, Vec3);
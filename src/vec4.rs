@@ -1,16 +1,18 @@
 #![allow(dead_code)]
 
-use std::ops::{Add, Sub, Neg, Mul, Div};
+use std::ops::{Add, Sub, Neg, Mul, Div, AddAssign, SubAssign, MulAssign, DivAssign,
+               Index, IndexMut};
 use std::cmp::{PartialEq, PartialOrd, Ordering};
 pub use num::{Zero, One, Num};
 use num;
 use super::float::Float;
 use super::{Vec2, Vec3};
 use std::fmt;
+use std::hash::{Hash, Hasher};
 use clamp::Clamp;
 use std::iter::IntoIterator;
 
-/// Vec4 is a generic four-component (3D) vector type.
+/// Vec4 is a generic four-component (4D) vector type.
 ///
 /// # Examples
 ///
@@ -30,12 +32,26 @@ use std::iter::IntoIterator;
 /// let y = Vec4(MM(1.0), MM(5.1), MM(1.9), MM(1.1));
 /// assert!(x.almost_equal(y, 0.1));
 /// ```
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, Debug, Default)]
+#[repr(C)]
 pub struct Vec4<T>(pub T, pub T, pub T, pub T);
 
 impl<T: Copy> IntoIterator for Vec4<T> {
     type Item = T;
     type IntoIter = Vec4Iterator<T>;
+
+    /// into_iter returns an iterator over the components of the vector, in
+    /// order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::Vec4;
+    ///
+    /// let v = Vec4(1, 2, 3, 4);
+    /// assert_eq!(v.into_iter().collect::<Vec<_>>(), vec![1, 2, 3, 4]);
+    /// assert_eq!(v.into_iter().sum::<i32>(), 10);
+    /// ```
     fn into_iter(self) -> Self::IntoIter {
         Vec4Iterator {
             v: self,
@@ -44,6 +60,7 @@ impl<T: Copy> IntoIterator for Vec4<T> {
     }
 }
 
+/// Vec4Iterator is an iterator over the components of a Vec4.
 pub struct Vec4Iterator<T> {
     v: Vec4<T>,
     index: usize,
@@ -64,6 +81,187 @@ impl<T: Copy> Iterator for Vec4Iterator<T> {
     }
 }
 
+impl<T> Index<usize> for Vec4<T> {
+    type Output = T;
+
+    /// index returns a reference to the component at the given index, i.e.
+    /// `v[0]` is the x component. It panics if the index is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::Vec4;
+    ///
+    /// let v = Vec4(1, 2, 3, 4);
+    /// assert_eq!(v[1], 2);
+    /// ```
+    fn index(&self, i: usize) -> &T {
+        match i {
+            0 => &self.0,
+            1 => &self.1,
+            2 => &self.2,
+            3 => &self.3,
+            _ => panic!("Vec4 index out of bounds: the len is 4 but the index is {}", i),
+        }
+    }
+}
+
+impl<T> IndexMut<usize> for Vec4<T> {
+    /// index_mut returns a mutable reference to the component at the given
+    /// index. It panics if the index is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::Vec4;
+    ///
+    /// let mut v = Vec4(1, 2, 3, 4);
+    /// v[3] = 9;
+    /// assert_eq!(v[3], 9);
+    /// ```
+    fn index_mut(&mut self, i: usize) -> &mut T {
+        match i {
+            0 => &mut self.0,
+            1 => &mut self.1,
+            2 => &mut self.2,
+            3 => &mut self.3,
+            _ => panic!("Vec4 index out of bounds: the len is 4 but the index is {}", i),
+        }
+    }
+}
+
+impl<T> From<(T, T, T, T)> for Vec4<T> {
+    /// from converts a tuple into a vector.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::Vec4;
+    ///
+    /// let v: Vec4<f32> = (1.0, 2.0, 3.0, 4.0).into();
+    /// assert_eq!(v, Vec4(1.0, 2.0, 3.0, 4.0));
+    /// ```
+    fn from(v: (T, T, T, T)) -> Self {
+        Vec4(v.0, v.1, v.2, v.3)
+    }
+}
+
+impl<T> From<Vec4<T>> for (T, T, T, T) {
+    /// from converts a vector into a tuple.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::Vec4;
+    ///
+    /// let t: (f32, f32, f32, f32) = Vec4(1.0, 2.0, 3.0, 4.0).into();
+    /// assert_eq!(t, (1.0, 2.0, 3.0, 4.0));
+    /// ```
+    fn from(v: Vec4<T>) -> Self {
+        (v.0, v.1, v.2, v.3)
+    }
+}
+
+impl<T> From<[T; 4]> for Vec4<T> {
+    /// from converts an array into a vector.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::Vec4;
+    ///
+    /// let v: Vec4<f32> = [1.0, 2.0, 3.0, 4.0].into();
+    /// assert_eq!(v, Vec4(1.0, 2.0, 3.0, 4.0));
+    /// ```
+    fn from(v: [T; 4]) -> Self {
+        let [x, y, z, w] = v;
+        Vec4(x, y, z, w)
+    }
+}
+
+impl<T> From<Vec4<T>> for [T; 4] {
+    /// from converts a vector into an array.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::Vec4;
+    ///
+    /// let a: [f32; 4] = Vec4::from([1.0, 2.0, 3.0, 4.0]).into();
+    /// assert_eq!(a, [1.0, 2.0, 3.0, 4.0]);
+    /// ```
+    fn from(v: Vec4<T>) -> Self {
+        [v.0, v.1, v.2, v.3]
+    }
+}
+
+impl<T> Vec4<T> {
+    /// map returns a vector whose components are the result of applying the
+    /// function `f` to each component of this vector. The component type may
+    /// change.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::Vec4;
+    ///
+    /// assert_eq!(Vec4(1.0, 2.0, 3.0, 4.0).map(|c| c as i32), Vec4(1, 2, 3, 4));
+    /// ```
+    pub fn map<U, F: Fn(T) -> U>(self, f: F) -> Vec4<U> {
+        Vec4(f(self.0), f(self.1), f(self.2), f(self.3))
+    }
+
+    /// zip returns a vector whose components are the result of applying the
+    /// function `f` to each pair of components from this vector and the other
+    /// one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::Vec4;
+    ///
+    /// let a = Vec4(1.0f32, 5.0, 3.0, 8.0);
+    /// let b = Vec4(4.0, 2.0, 6.0, 7.0);
+    /// assert_eq!(a.zip(b, |x, y| x.max(y)), Vec4(4.0, 5.0, 6.0, 8.0));
+    /// assert_eq!(a.zip(b, |x, y| x.min(y)), Vec4(1.0, 2.0, 3.0, 7.0));
+    /// ```
+    pub fn zip<U, V, F: Fn(T, U) -> V>(self, other: Vec4<U>, f: F) -> Vec4<V> {
+        Vec4(f(self.0, other.0), f(self.1, other.1), f(self.2, other.2), f(self.3, other.3))
+    }
+
+    /// truncate returns a three-component vector with the w component of this
+    /// vector dropped. Also see the xyz method.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::{Vec3, Vec4};
+    ///
+    /// let v = Vec4(1, 2, 3, 4);
+    /// assert_eq!(v.truncate(), Vec3(1, 2, 3));
+    /// assert_eq!(v.xyz(), Vec3(1, 2, 3));
+    /// assert_eq!(v.truncate().extend(v.w()), v);
+    /// ```
+    pub fn truncate(self) -> Vec3<T> {
+        Vec3(self.0, self.1, self.2)
+    }
+}
+
+impl<T: Copy> Vec4<T> {
+    /// splat returns a vector with every component set to `v`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::Vec4;
+    ///
+    /// assert_eq!(Vec4::splat(5), Vec4(5, 5, 5, 5));
+    /// ```
+    pub fn splat(v: T) -> Self {
+        Vec4(v, v, v, v)
+    }
+}
+
 impl<T: fmt::Display> fmt::Display for Vec4<T> {
     /// fmt formats the vector.
     ///
@@ -100,6 +298,60 @@ impl<T: One> One for Vec4<T> {
     }
 }
 
+impl<T: Zero + One> Vec4<T> {
+    /// unit_x returns the unit vector along the x axis.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::Vec4;
+    ///
+    /// assert_eq!(Vec4::<f32>::unit_x(), Vec4(1.0, 0.0, 0.0, 0.0));
+    /// ```
+    pub fn unit_x() -> Self {
+        Vec4(T::one(), T::zero(), T::zero(), T::zero())
+    }
+
+    /// unit_y returns the unit vector along the y axis.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::Vec4;
+    ///
+    /// assert_eq!(Vec4::<f32>::unit_y(), Vec4(0.0, 1.0, 0.0, 0.0));
+    /// ```
+    pub fn unit_y() -> Self {
+        Vec4(T::zero(), T::one(), T::zero(), T::zero())
+    }
+
+    /// unit_z returns the unit vector along the z axis.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::Vec4;
+    ///
+    /// assert_eq!(Vec4::<f32>::unit_z(), Vec4(0.0, 0.0, 1.0, 0.0));
+    /// ```
+    pub fn unit_z() -> Self {
+        Vec4(T::zero(), T::zero(), T::one(), T::zero())
+    }
+
+    /// unit_w returns the unit vector along the w axis.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::Vec4;
+    ///
+    /// assert_eq!(Vec4::<f32>::unit_w(), Vec4(0.0, 0.0, 0.0, 1.0));
+    /// ```
+    pub fn unit_w() -> Self {
+        Vec4(T::zero(), T::zero(), T::zero(), T::one())
+    }
+}
+
 impl<T: Float> Vec4<T> {
     /// almost_equal tells if this vector is equal to the other given an absolute
     /// tolerence value (see the almost_equal function for more details).
@@ -115,8 +367,32 @@ impl<T: Float> Vec4<T> {
     /// assert!(!a.almost_equal(b, 0.1));
     /// ```
     pub fn almost_equal<N: num::Float>(self, other: Self, abs_tol: N) -> bool {
-        self.0.almost_equal(other.0, abs_tol) && self.1.almost_equal(other.1, abs_tol) &&
-        self.2.almost_equal(other.2, abs_tol) && self.3.almost_equal(other.3, abs_tol)
+        almost_equal_all!(abs_tol;
+                          (self.0, other.0),
+                          (self.1, other.1),
+                          (self.2, other.2),
+                          (self.3, other.3))
+    }
+
+    /// almost_equal_rel tells if this vector is equal to the other given an
+    /// absolute and a relative tolerence value (see the almost_equal_rel
+    /// function for more details).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::Vec4;
+    ///
+    /// let a = Vec4(1.0e20, 1.0e20, 1.0e20, 1.0e20);
+    /// let b = Vec4(1.0e20, 1.0e20, 1.0e20, 1.0000001e20);
+    /// assert!(!a.almost_equal(b, 1e-8));
+    /// assert!(a.almost_equal_rel(b, 1e-8, 1e-6));
+    /// ```
+    pub fn almost_equal_rel<N: num::Float>(self, other: Self, abs_tol: N, rel_tol: N) -> bool {
+        self.0.almost_equal_rel(other.0, abs_tol, rel_tol) &&
+        self.1.almost_equal_rel(other.1, abs_tol, rel_tol) &&
+        self.2.almost_equal_rel(other.2, abs_tol, rel_tol) &&
+        self.3.almost_equal_rel(other.3, abs_tol, rel_tol)
     }
 
     /// is_nan tells if all of this vectors components are NaN.
@@ -155,6 +431,56 @@ impl<T: Float> Vec4<T> {
              self.2.round(),
              self.3.round())
     }
+
+    /// floor returns the vector with each component rounded down to the
+    /// nearest integer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::Vec4;
+    ///
+    /// assert_eq!(Vec4(1.4, 2.6, 3.5, -0.5).floor(), Vec4(1.0, 2.0, 3.0, -1.0))
+    /// ```
+    pub fn floor(&self) -> Self {
+        Vec4(self.0.floor(),
+             self.1.floor(),
+             self.2.floor(),
+             self.3.floor())
+    }
+
+    /// ceil returns the vector with each component rounded up to the nearest
+    /// integer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::Vec4;
+    ///
+    /// assert_eq!(Vec4(1.4, 2.6, 3.5, -0.5).ceil(), Vec4(2.0, 3.0, 4.0, -0.0))
+    /// ```
+    pub fn ceil(&self) -> Self {
+        Vec4(self.0.ceil(),
+             self.1.ceil(),
+             self.2.ceil(),
+             self.3.ceil())
+    }
+}
+
+impl<T: num::Signed> Vec4<T> {
+    /// abs returns the vector with the absolute value of each component.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::Vec4;
+    ///
+    /// assert_eq!(Vec4(-1.0, 2.0, -3.0, 4.0).abs(), Vec4(1.0, 2.0, 3.0, 4.0));
+    /// assert_eq!(Vec4(-1, 2, -3, 4).abs(), Vec4(1, 2, 3, 4));
+    /// ```
+    pub fn abs(&self) -> Self {
+        Vec4(self.0.abs(), self.1.abs(), self.2.abs(), self.3.abs())
+    }
 }
 
 impl<T: Add<Output = T>> Add for Vec4<T> {
@@ -193,6 +519,36 @@ impl<T: Add<Output = T> + Copy> Vec4<T> {
     pub fn add_scalar(self, _rhs: T) -> Self {
         Vec4(self.0 + _rhs, self.1 + _rhs, self.2 + _rhs, self.3 + _rhs)
     }
+
+    /// sum returns the sum of all of the components of the vector.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::Vec4;
+    ///
+    /// assert_eq!(Vec4(1, 2, 3, 4).sum(), 10);
+    /// ```
+    pub fn sum(self) -> T {
+        self.0 + self.1 + self.2 + self.3
+    }
+}
+
+impl<T: Add<Output = T> + Copy> AddAssign for Vec4<T> {
+    /// add_assign performs in-place component-wise addition of two vectors.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::Vec4;
+    ///
+    /// let mut a = Vec4(1, 2, 3, 4);
+    /// a += Vec4(4, 5, 6, 7);
+    /// assert_eq!(a, Vec4(5, 7, 9, 11));
+    /// ```
+    fn add_assign(&mut self, _rhs: Self) {
+        *self = *self + _rhs;
+    }
 }
 
 impl<T: Neg<Output = T>> Neg for Vec4<T> {
@@ -250,6 +606,23 @@ impl<T: Sub<Output = T> + Copy> Vec4<T> {
     }
 }
 
+impl<T: Sub<Output = T> + Copy> SubAssign for Vec4<T> {
+    /// sub_assign performs in-place component-wise subtraction of two vectors.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::Vec4;
+    ///
+    /// let mut a = Vec4(1, 2, 3, 4);
+    /// a -= Vec4(4, 5, 6, 7);
+    /// assert_eq!(a, Vec4(-3, -3, -3, -3));
+    /// ```
+    fn sub_assign(&mut self, _rhs: Self) {
+        *self = *self - _rhs;
+    }
+}
+
 impl<T: Mul<Output = T>> Mul for Vec4<T> {
     type Output = Self;
 
@@ -286,6 +659,74 @@ impl<T: Mul<Output = T> + Copy> Vec4<T> {
     pub fn mul_scalar(self, _rhs: T) -> Self {
         Vec4(self.0 * _rhs, self.1 * _rhs, self.2 * _rhs, self.3 * _rhs)
     }
+
+    /// product returns the product of all of the components of the vector,
+    /// e.g. the volume of a box whose size is the vector.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::Vec4;
+    ///
+    /// assert_eq!(Vec4(2, 3, 4, 5).product(), 120);
+    /// ```
+    pub fn product(self) -> T {
+        self.0 * self.1 * self.2 * self.3
+    }
+}
+
+impl<T: Mul<Output = T> + Copy> Mul<T> for Vec4<T> {
+    type Output = Self;
+
+    /// mul performs scalar multiplication on a vector, it is identical to
+    /// mul_scalar.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::Vec4;
+    ///
+    /// assert_eq!(Vec4(1, 2, 3, 4) * 2, Vec4(2, 4, 6, 8));
+    /// assert_eq!(Vec4(1.5, 2.0, 3.0, 4.0) * 2.0, Vec4(3.0, 4.0, 6.0, 8.0));
+    /// ```
+    fn mul(self, _rhs: T) -> Self {
+        Vec4(self.0 * _rhs, self.1 * _rhs, self.2 * _rhs, self.3 * _rhs)
+    }
+}
+
+impl<T: Mul<Output = T> + Copy> MulAssign for Vec4<T> {
+    /// mul_assign performs in-place component-wise multiplication of two
+    /// vectors.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::Vec4;
+    ///
+    /// let mut a = Vec4(1, 2, 3, 4);
+    /// a *= Vec4(4, 5, 6, 7);
+    /// assert_eq!(a, Vec4(4, 10, 18, 28));
+    /// ```
+    fn mul_assign(&mut self, _rhs: Self) {
+        *self = *self * _rhs;
+    }
+}
+
+impl<T: Mul<Output = T> + Copy> MulAssign<T> for Vec4<T> {
+    /// mul_assign performs in-place scalar multiplication on a vector.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::Vec4;
+    ///
+    /// let mut a = Vec4(1.0, 2.0, 3.0, 4.0);
+    /// a *= 2.0;
+    /// assert_eq!(a, Vec4(2.0, 4.0, 6.0, 8.0));
+    /// ```
+    fn mul_assign(&mut self, _rhs: T) {
+        *self = *self * _rhs;
+    }
 }
 
 impl<T: Div<Output = T>> Div for Vec4<T> {
@@ -326,6 +767,59 @@ impl<T: Div<Output = T> + Copy> Vec4<T> {
     }
 }
 
+impl<T: Div<Output = T> + Copy> Div<T> for Vec4<T> {
+    type Output = Self;
+
+    /// div performs scalar division on a vector, it is identical to
+    /// div_scalar. Integer components are truncated as usual.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::Vec4;
+    ///
+    /// assert_eq!(Vec4(3, 5, 6, 9) / 2, Vec4(1, 2, 3, 4));
+    /// assert_eq!(Vec4(2.0, 4.0, 6.0, 8.0) / 2.0, Vec4(1.0, 2.0, 3.0, 4.0));
+    /// ```
+    fn div(self, _rhs: T) -> Self {
+        Vec4(self.0 / _rhs, self.1 / _rhs, self.2 / _rhs, self.3 / _rhs)
+    }
+}
+
+impl<T: Div<Output = T> + Copy> DivAssign for Vec4<T> {
+    /// div_assign performs in-place component-wise division of two vectors.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::Vec4;
+    ///
+    /// let mut a = Vec4(4, 5, 9, 8);
+    /// a /= Vec4(1, 2, 3, 4);
+    /// assert_eq!(a, Vec4(4, 2, 3, 2));
+    /// ```
+    fn div_assign(&mut self, _rhs: Self) {
+        *self = *self / _rhs;
+    }
+}
+
+impl<T: Div<Output = T> + Copy> DivAssign<T> for Vec4<T> {
+    /// div_assign performs in-place scalar division on a vector.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::Vec4;
+    ///
+    /// let mut a = Vec4(2.0, 4.0, 6.0, 8.0);
+    /// a /= 2.0;
+    /// assert_eq!(a, Vec4(1.0, 2.0, 3.0, 4.0));
+    /// ```
+    fn div_assign(&mut self, _rhs: T) {
+        *self = *self / _rhs;
+    }
+}
+
 impl<T: Clamp<Elem = T> + Copy> Clamp for Vec4<T> {
     type Elem = T;
 
@@ -413,6 +907,34 @@ impl<T: PartialEq> PartialEq for Vec4<T> {
     }
 }
 
+impl<T: Eq> Eq for Vec4<T> {}
+
+impl<T: Hash> Hash for Vec4<T> {
+    /// hash feeds each component of the vector, in order, into the given
+    /// hasher. Floating-point vectors do not implement Hash, as their
+    /// components do not.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::HashSet;
+    /// use fiz_math::Vec4;
+    ///
+    /// let mut voxels = HashSet::new();
+    /// voxels.insert(Vec4(1, 2, 3, 4));
+    /// voxels.insert(Vec4(4, 5, 6, 7));
+    /// voxels.insert(Vec4(1, 2, 3, 4));
+    /// assert_eq!(voxels.len(), 2);
+    /// assert!(voxels.contains(&Vec4(4, 5, 6, 7)));
+    /// ```
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.hash(state);
+        self.1.hash(state);
+        self.2.hash(state);
+        self.3.hash(state);
+    }
+}
+
 impl<T: PartialOrd> PartialOrd for Vec4<T> {
     /// partial_cmp compares the two vectors component-wise.
     ///
@@ -503,6 +1025,24 @@ impl<T: PartialOrd> Vec4<T> {
                  other.3
              })
     }
+
+    /// clamp_vec returns the vector with each component clamped to the range
+    /// of the corresponding components in the `min` and `max` vectors. If a
+    /// component of `min` is greater than the one in `max`, then the `max`
+    /// component is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::Vec4;
+    ///
+    /// let a = Vec4(-1, 5, 3, 2);
+    /// assert_eq!(a.clamp_vec(Vec4(0, 0, 0, 0), Vec4(4, 4, 4, 4)), Vec4(0, 4, 3, 2));
+    /// assert_eq!(a.clamp_vec(Vec4(5, 5, 5, 5), Vec4(3, 3, 3, 3)), Vec4(3, 3, 3, 3));
+    /// ```
+    pub fn clamp_vec(self, min: Self, max: Self) -> Self {
+        self.max(min).min(max)
+    }
 }
 
 impl<T: Zero> Zero for Vec4<T> {
@@ -584,6 +1124,45 @@ impl<T: Num + Copy> Vec4<T> {
     pub fn project(self, other: Self) -> Self {
         other.mul_scalar(self.dot(other) / other.length_sq())
     }
+
+    /// reject returns a vector representing the rejection of the `self` vector
+    /// from the `other` vector, that is the component of `self` perpendicular
+    /// to `other`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::Vec4;
+    ///
+    /// let a = Vec4(1.0f64, 2.0, 4.0, 1.0);
+    /// let b = Vec4(1.0, 2.0, 3.0, 1.0);
+    /// assert!(a.reject(b).dot(b).abs() < 1e-10);
+    /// assert!((a.project(b) + a.reject(b)).almost_equal(a, 1e-10));
+    /// ```
+    pub fn reject(self, other: Self) -> Self {
+        self - self.project(other)
+    }
+}
+
+impl<T: Num + PartialOrd + Copy> Vec4<T> {
+    /// distance_sq returns the distance squared between the `self` and `other`
+    /// vectors, useful primarily for comparing distances. The difference of each
+    /// component is taken such that unsigned vectors do not underflow.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::Vec4;
+    ///
+    /// assert_eq!(Vec4(1, 2, 3, 4).distance_sq(Vec4(4, 6, 3, 4)), 25);
+    /// assert_eq!(Vec4(0.0, 0.0, 0.0, 0.0).distance_sq(Vec4(3.0, 4.0, 0.0, 0.0)), 25.0);
+    /// assert_eq!(Vec4(2u32, 0, 0, 0).distance_sq(Vec4(1, 0, 0, 0)), 1);
+    /// ```
+    pub fn distance_sq(self, other: Self) -> T {
+        let d = |a: T, b: T| if a > b { a - b } else { b - a };
+        Vec4(d(self.0, other.0), d(self.1, other.1), d(self.2, other.2), d(self.3, other.3))
+            .length_sq()
+    }
 }
 
 impl<T: Float> Vec4<T> {
@@ -603,6 +1182,21 @@ impl<T: Float> Vec4<T> {
         self.length_sq().sqrt()
     }
 
+    /// distance returns the distance between the `self` and `other` vectors.
+    /// Use distance_sq instead when comparing distances, because it avoids the
+    /// extra sqrt operation needed by this method.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::Vec4;
+    ///
+    /// assert_eq!(Vec4(0.0, 0.0, 0.0, 0.0).distance(Vec4(3.0, 4.0, 0.0, 0.0)), 5.0);
+    /// ```
+    pub fn distance(self, other: Self) -> T {
+        (other - self).length()
+    }
+
     /// normalize returns the normalized (i.e. length/magnitude == 1) vector
     /// representing self. If the vector's length is zero and division by zero
     /// would occur, then None is returned.
@@ -635,10 +1229,16 @@ impl<T: Float> Vec4<T> {
     ///
     /// let a = Vec4(1.25, 1.25, 1.25, 1.25);
     /// let b = Vec4(2.0, 2.0, 2.0, 2.0);
-    /// assert_eq!(a.lerp(b, 0.25), Vec4(0.625, 0.625, 0.625, 0.625));
+    /// assert_eq!(a.lerp(b, 0.0), a);
+    /// assert_eq!(a.lerp(b, 0.25), Vec4(1.4375, 1.4375, 1.4375, 1.4375));
+    /// assert_eq!(a.lerp(b, 0.5), Vec4(1.625, 1.625, 1.625, 1.625));
+    /// assert_eq!(a.lerp(b, 1.0), b);
     /// ```
     pub fn lerp(self, other: Self, t: T) -> Self {
-        self * other.mul_scalar(t)
+        Vec4(self.0.lerp(other.0, t),
+             self.1.lerp(other.1, t),
+             self.2.lerp(other.2, t),
+             self.3.lerp(other.3, t))
     }
 }
 
@@ -647,5 +1247,6 @@ swizzle!(y, Vec4);
 swizzle!(z, Vec4);
 swizzle!(w, Vec4);
 swizzle!(2, Vec4);
+swizzle!(2, z, Vec4);
 swizzle!(3, Vec4);
 swizzle!(4, Vec4);